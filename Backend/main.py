@@ -2,24 +2,49 @@ import uvicorn
 import asyncio
 import logging
 import argparse
+import os
+import signal
 from contextlib import asynccontextmanager
 from pathlib import Path
+from typing import Optional
+from types import MappingProxyType
 import time
+import json
+import uuid
 
-from fastapi import FastAPI, HTTPException, WebSocket, WebSocketDisconnect, status
+from fastapi import FastAPI, HTTPException, Request, WebSocket, WebSocketDisconnect, status
+from fastapi.exceptions import RequestValidationError
+from fastapi.encoders import jsonable_encoder
 from fastapi.middleware.cors import CORSMiddleware
 from fastapi.staticfiles import StaticFiles
-from fastapi.responses import JSONResponse
+from fastapi.responses import JSONResponse, Response
 
 # Import our modules
-from src.api.endpoints import health, models, training, plugins, gpu
+from src.api.endpoints import health, models, training, plugins, gpu, profiles, config, features, metrics, users, diagnostics, pipeline
 from src.core.gpu_manager import UniversalGPUManager
 from src.core.model_factory import ModelFactory
 from src.core.training_engine import TrainingEngine
+from src.core.profile_manager import ProfileManager
 from src.plugins.plugin_manager import PluginManager
 from src.utils.config import Settings
 from src.utils.logging_config import setup_logging
 from src.utils.websocket_manager import WebSocketManager
+from src.utils.health_state import HealthState
+from src.utils.config_reload import reload_settings
+from src.utils.rate_limiter import RateLimiter
+from src.utils.inflight_tracker import InflightTracker, InflightMiddleware
+from src.utils.access_log import AccessLogger, AccessLogMiddleware
+from src.utils.traffic_recorder import TrafficRecorder, TrafficRecordingMiddleware
+from src.utils.traffic_replay import replay_traffic
+from src.utils.idempotency_cache import IdempotencyCache, idempotency_cache_key, is_cacheable
+from src.utils.feature_flags import FeatureFlags, FEATURE_ROUTES
+from src.utils.circuit_breaker import CircuitBreaker
+from src.utils.readiness import ReadinessChecks
+from src.utils.metrics_registry import MetricsRegistry
+from src.utils.access_control import AccessControl
+from src.utils.route_filter import RouteFilter
+from src.utils.request_logging import RequestLoggingMiddleware
+from src.utils.diagnostics import run_diagnostics, write_diagnostics_report
 
 # Global managers
 gpu_manager: UniversalGPUManager = UniversalGPUManager()
@@ -27,56 +52,272 @@ model_factory: ModelFactory = ModelFactory(gpu_manager=gpu_manager)
 training_engine: TrainingEngine = TrainingEngine(gpu_manager, model_factory)
 plugin_manager: PluginManager = PluginManager(plugin_directory = Path(__file__).parent / "src" / "plugins")
 websocket_manager: WebSocketManager = WebSocketManager()
+profile_manager: ProfileManager = ProfileManager(gpu_manager, plugin_manager)
 settings: Settings = Settings()
+rate_limiter: RateLimiter = RateLimiter(settings.rate_limit_requests, settings.rate_limit_window_seconds)
+inflight_tracker: InflightTracker = InflightTracker()
+access_logger: Optional[AccessLogger] = None
+traffic_recorder: Optional[TrafficRecorder] = None
+idempotency_cache: IdempotencyCache = IdempotencyCache(settings.idempotency_ttl_seconds)
+feature_flags: FeatureFlags = FeatureFlags(settings.feature_flags_path)
+access_control: AccessControl = AccessControl(settings.access_control_path, settings.admin_api_key)
+route_filter: RouteFilter = RouteFilter()
+
+def create_circuit_breakers(breaker_settings: Settings) -> dict:
+    """One circuit breaker per feature-gated subsystem"""
+    return {
+        subsystem: CircuitBreaker(subsystem, breaker_settings.circuit_failure_threshold, breaker_settings.circuit_cooldown_seconds)
+        for subsystem, prefixes in FEATURE_ROUTES.items() if prefixes
+    }
+
+circuit_breakers: dict = create_circuit_breakers(settings)
+
+def record_startup_timing(timings: dict, subsystem: str, started_at: float, max_duration_ms: int):
+    """Record how long a subsystem took to initialize, warning when it is slow"""
+    elapsed_ms = (time.perf_counter() - started_at) * 1000
+    timings[subsystem] = round(elapsed_ms, 2)
+    if elapsed_ms > max_duration_ms:
+        logging.warning(f"⚠️ {subsystem} took {elapsed_ms:.0f}ms to initialize (limit {max_duration_ms}ms)")
+
+def create_metrics_registry() -> MetricsRegistry:
+    """Register the backend's metrics; values are read from the managers at scrape time"""
+    registry = MetricsRegistry()
+    registry.register_gauge(
+        "archetype_inflight_requests", "HTTP requests currently being handled",
+        lambda: [({}, inflight_tracker.count)]
+    )
+    registry.register_gauge(
+        "archetype_loaded_plugins", "Plugins currently loaded",
+        lambda: [({}, len(plugin_manager.get_loaded_plugins()))]
+    )
+    registry.register_counter(
+        "archetype_plugin_calls", "Component creation calls made to each plugin",
+        lambda: [({"plugin_id": pid}, s["total_calls"]) for pid, s in plugin_manager.get_plugin_metrics().items()]
+    )
+    registry.register_counter(
+        "archetype_plugin_call_errors", "Component creation calls that raised",
+        lambda: [({"plugin_id": pid}, s["error_count"]) for pid, s in plugin_manager.get_plugin_metrics().items()]
+    )
+    registry.register_histogram(
+        "archetype_plugin_call_duration_seconds", "Duration of plugin calls by component type",
+        lambda: [
+            ({"plugin_id": pid, "function": function}, histogram)
+            for (pid, function), histogram in plugin_manager.plugin_call_durations.items()
+        ],
+        unit="seconds"
+    )
+    registry.register_counter(
+        "archetype_route_blocked", "Requests rejected by the route allowlist or denylist",
+        lambda: [({"rule": rule}, count) for rule, count in route_filter.blocked_counts.items()]
+    )
+    registry.register_gauge(
+        "archetype_circuit_breaker_open", "1 while a subsystem's circuit breaker is not closed",
+        lambda: [({"subsystem": name}, int(b.state.value != "closed")) for name, b in circuit_breakers.items()]
+    )
+    registry.register_gauge(
+        "archetype_uptime_seconds", "Time since startup completed",
+        lambda: [({}, round(time.time() - getattr(app.state, 'startup_time', time.time()), 3))],
+        unit="seconds"
+    )
+    return registry
+
+async def announce_when_ready(readiness_checks: ReadinessChecks, manager: WebSocketManager, interval_seconds: float = 1.0):
+    """Wait for a degraded startup to pass its readiness checks, then emit startup_complete"""
+    while not readiness_checks.evaluate()[0]:
+        await asyncio.sleep(interval_seconds)
+    app.state.health_state = HealthState.READY
+    logging.info("✅ All readiness checks passing")
+    await manager.mark_startup_complete()
+
+def store_app_state(startup_timings: dict, readiness_checks: ReadinessChecks):
+    """Store the managers in app state for access by endpoints"""
+    app.state.gpu_manager = gpu_manager
+    app.state.model_factory = model_factory
+    app.state.training_engine = training_engine
+    app.state.plugin_manager = plugin_manager
+    app.state.websocket_manager = websocket_manager
+    app.state.profile_manager = profile_manager
+    app.state.services = plugin_manager.services
+    app.state.rate_limiter = rate_limiter
+    app.state.inflight_tracker = inflight_tracker
+    app.state.idempotency_cache = idempotency_cache
+    app.state.feature_flags = feature_flags
+    app.state.access_control = access_control
+    app.state.route_filter = route_filter
+    app.state.circuit_breakers = circuit_breakers
+    app.state.settings = settings
+    app.state.startup_time = time.time()
+    app.state.startup_timings = MappingProxyType(startup_timings)  # read-only once startup completes
+    
+    app.state.readiness_checks = readiness_checks
+    app.state.metrics_registry = create_metrics_registry()
+
+def init_state_for_testing(gpu_backend, plugin_backend, test_settings: Optional[Settings] = None):
+    """Install GPU and plugin backends (usually mocks) as if startup had completed, without running the lifespan"""
+    global gpu_manager, model_factory, training_engine, plugin_manager, websocket_manager, profile_manager, settings
+    global rate_limiter, idempotency_cache, feature_flags, access_control, route_filter, circuit_breakers
+    
+    settings = test_settings or Settings()
+    gpu_manager = gpu_backend
+    model_factory = ModelFactory(gpu_backend)
+    training_engine = TrainingEngine(gpu_backend, model_factory)
+    plugin_manager = plugin_backend
+    websocket_manager = WebSocketManager()
+    profile_manager = ProfileManager(gpu_backend, plugin_backend, settings.profile_directory)
+    
+    # Request-path state starts empty so tests never see each other's rate limits or cached responses
+    rate_limiter = RateLimiter(settings.rate_limit_requests, settings.rate_limit_window_seconds)
+    idempotency_cache = IdempotencyCache(settings.idempotency_ttl_seconds)
+    feature_flags = FeatureFlags(settings.feature_flags_path)
+    access_control = AccessControl(settings.access_control_path, settings.admin_api_key)
+    route_filter = RouteFilter()
+    circuit_breakers = create_circuit_breakers(settings)
+    
+    plugin_manager.services.register_service("gpu_manager", gpu_manager)
+    plugin_manager.services.register_service("config_store", settings.without_secrets())
+    plugin_manager.services.register_service("broadcast_channel", websocket_manager)
+    
+    store_app_state({}, ReadinessChecks())
+    app.state.health_state = HealthState.READY
+
+class ArchetypeServer(uvicorn.Server):
+    """Uvicorn server that warns WebSocket clients before it starts closing connections"""
+    
+    def handle_exit(self, sig, frame):
+        manager = getattr(app.state, 'websocket_manager', None)
+        if manager and not self.should_exit:
+            try:
+                loop = asyncio.get_running_loop()
+                loop.call_soon_threadsafe(lambda: loop.create_task(manager.announce_shutdown()))
+            except RuntimeError:
+                pass
+        super().handle_exit(sig, frame)
+    
+    async def shutdown(self, sockets=None):
+        # Let in-flight requests finish before uvicorn closes the listening sockets and open connections
+        current_settings = getattr(app.state, 'settings', settings)
+        if not await inflight_tracker.wait_for_drain(current_settings.drain_timeout_ms / 1000):
+            logging.warning(f"⚠️ Shutting down with {inflight_tracker.count} requests still in flight")
+        await super().shutdown(sockets=sockets)
 
 @asynccontextmanager
 async def lifespan(app: FastAPI):
     """Application lifespan manager - startup and shutdown"""
-    global gpu_manager, model_factory, training_engine, plugin_manager, websocket_manager, settings
+    global gpu_manager, model_factory, training_engine, plugin_manager, websocket_manager, profile_manager, settings, access_logger, traffic_recorder
     
     # Startup
     logging.info("🚀 Starting Archetype Neural Network Backend...")
+    app.state.health_state = HealthState.STARTING
+    startup_watcher: Optional[asyncio.Task] = None
     
     try:
         # Load configuration
         settings = Settings()
+        startup_timings = {}
+        max_ms = settings.max_startup_duration_ms
+        
+        # Access log in Common Log Format, separate from the application log
+        if settings.access_log_path:
+            access_logger = AccessLogger(settings.access_log_path)
+            access_logger.start()
+        
+        # Request/response capture for replaying a session against a new build
+        if settings.record_traffic:
+            traffic_recorder = TrafficRecorder(settings.traffic_log_path)
+            traffic_recorder.start()
         
         # Initialize GPU manager first
-        gpu_manager = UniversalGPUManager()
-        await gpu_manager.initialize()
-        logging.info(f"✅ GPU Manager initialized - {gpu_manager.get_device_info()}")
+        started_at = time.perf_counter()
+        if settings.gpu_mock_file and not settings.allow_gpu_mock:
+            raise ValueError("gpu_mock_file is set but allow_gpu_mock is false; mock GPU data is not allowed here")
+        gpu_manager = UniversalGPUManager(mock_file=settings.gpu_mock_file)
+        if settings.lazy_gpu_init:
+            logging.info("⏳ GPU Manager initialization deferred until first use")
+        else:
+            await gpu_manager.initialize()
+            logging.info(f"✅ GPU Manager initialized - {gpu_manager.get_device_info()}")
+        record_startup_timing(startup_timings, "gpu_manager", started_at, max_ms)
         
         # Initialize model factory
+        started_at = time.perf_counter()
         model_factory = ModelFactory(gpu_manager)
         await model_factory.initialize()
+        record_startup_timing(startup_timings, "model_factory", started_at, max_ms)
         logging.info("✅ Model Factory initialized")
         
         # Initialize training engine
+        started_at = time.perf_counter()
         training_engine = TrainingEngine(gpu_manager, model_factory)
         await training_engine.initialize()
+        record_startup_timing(startup_timings, "training_engine", started_at, max_ms)
         logging.info("✅ Training Engine initialized")
         
+        # Readiness conditions; plugins can add their own through the "readiness_checks" service
+        readiness_checks = ReadinessChecks()
+        # With lazy init the device is detected on first use, so a pending initialization does not block readiness
+        lazy_gpu_init = settings.lazy_gpu_init
+        readiness_checks.register_readiness_check(
+            "gpu_device_initialized", lambda: lazy_gpu_init or gpu_manager.is_initialized()
+        )
+        
+        # Initialize WebSocket manager before the plugins, which get it as their broadcast channel
+        websocket_manager = WebSocketManager()
+        logging.info("✅ WebSocket Manager initialized")
+        
         # Initialize plugin manager
-        plugin_manager = PluginManager(settings.plugin_directory)
+        started_at = time.perf_counter()
+        plugin_manager = PluginManager(settings.plugin_directory, log_max_bytes=settings.plugin_log_max_bytes)
+        plugin_manager.version_history_count = settings.plugin_version_history_count
+        plugin_manager.max_call_depth = settings.max_plugin_call_depth
+        plugin_manager.rollback_window_ms = settings.plugin_rollback_window_ms
+        plugin_manager.environment = settings.environment
+        plugin_manager.services.register_service("gpu_manager", gpu_manager)
+        plugin_manager.services.register_service("config_store", settings.without_secrets())
+        plugin_manager.services.register_service("readiness_checks", readiness_checks)
+        plugin_manager.services.register_service("broadcast_channel", websocket_manager)
+        readiness_checks.register_readiness_check(
+            "all_autoload_plugins_loaded",
+            lambda: all(
+                pid in plugin_manager.loaded_plugins
+                for pid in [*plugin_manager.core_plugin_modules, *plugin_manager.autoload_plugins]
+            )
+        )
+        app.state.health_state = HealthState.LIVE
         await plugin_manager.load_core_plugins()
+        await plugin_manager.load_autoload_plugins()
+        record_startup_timing(startup_timings, "plugin_manager", started_at, max_ms)
         logging.info(f"✅ Plugin Manager initialized - {len(plugin_manager.get_loaded_plugins())} plugins loaded")
         
-        # Initialize WebSocket manager
-        websocket_manager = WebSocketManager()
-        logging.info("✅ WebSocket Manager initialized")
+        if settings.run_plugin_self_tests:
+            reports = await plugin_manager.run_all_self_tests()
+            failed = [r.plugin_id for r in reports if not r.passed]
+            logging.info(f"🧪 Plugin self-tests complete - {len(reports) - len(failed)}/{len(reports)} passed")
+        
+        # Initialize profile manager
+        started_at = time.perf_counter()
+        profile_manager = ProfileManager(gpu_manager, plugin_manager, settings.profile_directory)
+        record_startup_timing(startup_timings, "profile_manager", started_at, max_ms)
+        logging.info(f"✅ Profile Manager initialized - {len(await profile_manager.list_profiles())} profiles available")
+        
+        store_app_state(startup_timings, readiness_checks)
         
-        # Store managers in app state for access by endpoints
-        app.state.gpu_manager = gpu_manager
-        app.state.model_factory = model_factory
-        app.state.training_engine = training_engine
-        app.state.plugin_manager = plugin_manager
-        app.state.websocket_manager = websocket_manager
-        app.state.settings = settings
-        app.state.startup_time = time.time()
+        ready, failing_checks = readiness_checks.evaluate()
+        if ready:
+            app.state.health_state = HealthState.READY
+            await websocket_manager.mark_startup_complete()
+        else:
+            app.state.health_state = HealthState.DEGRADED
+            logging.warning(f"⚠️ Backend started in degraded state - failing readiness checks: {failing_checks}")
+            startup_watcher = asyncio.create_task(announce_when_ready(readiness_checks, websocket_manager))
         
-        logging.info("🎉 Archetype Backend startup complete!")
+        # SIGHUP reloads configuration (POSIX only - use POST /api/v1/config/reload on Windows)
+        if hasattr(signal, "SIGHUP"):
+            asyncio.get_running_loop().add_signal_handler(signal.SIGHUP, reload_settings, app.state)
+        
+        logging.info(f"🎉 Archetype Backend startup complete! Timings (ms): {dict(startup_timings)}")
         
     except Exception as e:
+        app.state.health_state = HealthState.FAILED
         logging.error(f"❌ Failed to initialize backend: {e}")
         raise
     
@@ -85,6 +326,12 @@ async def lifespan(app: FastAPI):
     # Shutdown
     logging.info("🛑 Shutting down Archetype Backend...")
     
+    if startup_watcher:
+        startup_watcher.cancel()
+    
+    if hasattr(signal, "SIGHUP"):
+        asyncio.get_running_loop().remove_signal_handler(signal.SIGHUP)
+    
     if training_engine:
         await training_engine.shutdown()
         logging.info("✅ Training Engine shutdown")
@@ -97,6 +344,12 @@ async def lifespan(app: FastAPI):
         await gpu_manager.cleanup()
         logging.info("✅ GPU Manager cleanup")
     
+    if access_logger:
+        await access_logger.stop()
+    
+    if traffic_recorder:
+        await traffic_recorder.stop()
+    
     logging.info("👋 Archetype Backend shutdown complete")
 
 # Create FastAPI application
@@ -118,12 +371,201 @@ app.add_middleware(
     allow_headers=["*"],
 )
 
+# Optional response envelope with versioning metadata; declared first so it wraps the endpoint's own JSON
+@app.middleware("http")
+async def response_envelope_middleware(request: Request, call_next):
+    current_settings = getattr(request.app.state, 'settings', settings)
+    if not current_settings.enable_response_envelope or not request.url.path.startswith("/api/v1"):
+        return await call_next(request)
+    
+    started_at = time.perf_counter()
+    response = await call_next(request)
+    if not response.headers.get("content-type", "").startswith("application/json"):
+        return response
+    
+    body = b"".join([chunk async for chunk in response.body_iterator])
+    route = request.scope.get("route")
+    envelope = {
+        "api_version": "v1",
+        "command": f"{request.method} {route.path if route else request.url.path}",
+        "request_id": request.headers.get("x-request-id") or uuid.uuid4().hex,
+        "timestamp_ms": int(time.time() * 1000),
+        "duration_ms": round((time.perf_counter() - started_at) * 1000, 3),
+        "data": json.loads(body) if body else None
+    }
+    # The endpoint's ETag hashes the unwrapped data, and the envelope's timestamp changes on every response
+    headers = {k: v for k, v in response.headers.items() if k.lower() not in ("content-length", "etag")}
+    return JSONResponse(status_code=response.status_code, content=envelope, headers=headers)
+
+# Per-route request logging verbosity; verbose routes also log request and response bodies
+app.add_middleware(RequestLoggingMiddleware, settings_provider=lambda: getattr(app.state, 'settings', settings))
+
+# In-flight request tracking for graceful shutdown
+app.add_middleware(InflightMiddleware, tracker=inflight_tracker)
+
+# Route allowlist/denylist for hardened deployments, read from the current settings so SIGHUP reloads it
+@app.middleware("http")
+async def route_filter_middleware(request: Request, call_next):
+    current_settings = getattr(request.app.state, 'settings', settings)
+    if not route_filter.is_allowed(request.url.path, current_settings.route_allowlist, current_settings.route_denylist):
+        return JSONResponse(
+            status_code=403,
+            content={"error": "RouteForbidden", "message": f"{request.url.path} is disabled in this deployment"}
+        )
+    return await call_next(request)
+
+# Circuit breakers fast-fail a subsystem's routes after repeated server errors
+@app.middleware("http")
+async def circuit_breaker_middleware(request: Request, call_next):
+    subsystem = feature_flags.feature_for_path(request.url.path)
+    breaker = circuit_breakers.get(subsystem)
+    if breaker is None:
+        return await call_next(request)
+    
+    if not breaker.allow_request():
+        return JSONResponse(
+            status_code=503,
+            content={"error": "CircuitBreakerOpen", "message": f"The {subsystem} subsystem is failing; retry later"}
+        )
+    
+    try:
+        response = await call_next(request)
+    except BaseException:
+        # Includes cancellation, so an abandoned half-open probe never leaves the breaker stuck
+        breaker.record_failure()
+        raise
+    
+    if response.status_code >= 500:
+        breaker.record_failure()
+    else:
+        breaker.record_success()
+    return response
+
+# Feature flags can switch off whole API areas at runtime
+@app.middleware("http")
+async def feature_flag_middleware(request: Request, call_next):
+    feature = feature_flags.disabled_feature_for_request(request.method, request.url.path, request.headers)
+    if feature:
+        return JSONResponse(
+            status_code=503,
+            content={"error": "Feature disabled", "message": f"The {feature} feature is currently disabled"}
+        )
+    return await call_next(request)
+
+# Retried POSTs carrying the same X-Idempotency-Key get the first response instead of re-executing
+@app.middleware("http")
+async def idempotency_middleware(request: Request, call_next):
+    idempotency_key = request.headers.get("x-idempotency-key")
+    if request.method != "POST" or not idempotency_key:
+        return await call_next(request)
+    
+    client = request.client.host if request.client else "unknown"
+    cache_key = idempotency_cache_key(client, request.headers.get("x-api-key", ""), request.url.path, idempotency_key)
+    is_new, cached = idempotency_cache.claim(cache_key)
+    
+    if not is_new:
+        wait_timeout = getattr(request.app.state, 'settings', settings).idempotency_wait_timeout_seconds
+        try:
+            replay = await idempotency_cache.wait(cached, wait_timeout)
+        except asyncio.TimeoutError:
+            return JSONResponse(
+                status_code=409,
+                content={"error": "IdempotencyKeyInProgress", "message": "A request with this X-Idempotency-Key is still running"}
+            )
+        if replay is not None:
+            status_code, body, headers = replay
+            return Response(content=body, status_code=status_code,
+                            headers={**headers, "X-Idempotent-Replay": "true"})
+        # The first attempt failed and was not cached - execute this one normally
+        return await call_next(request)
+    
+    replay = None
+    try:
+        response = await call_next(request)
+        body = b"".join([chunk async for chunk in response.body_iterator])
+        headers = {k: v for k, v in response.headers.items() if k.lower() != "content-length"}
+        
+        # Server errors and 401/403/429 are not cached so the client can retry them
+        if is_cacheable(response.status_code):
+            replay = (response.status_code, body, headers)
+        return Response(content=body, status_code=response.status_code, headers=headers)
+    finally:
+        # Resolved even when the request fails or is cancelled, so waiters never hang and the entry can expire
+        idempotency_cache.resolve(cache_key, replay)
+
+# Role-based access control, active once users are configured; declared between idempotency and rate
+# limiting so unauthorized requests never reach the response cache but still count against the rate limit
+@app.middleware("http")
+async def access_control_middleware(request: Request, call_next):
+    if access_control.enabled and request.method != "OPTIONS":
+        status_code, error = access_control.authorize(
+            request.headers.get("x-api-key", ""), request.method, request.url.path
+        )
+        if error:
+            return JSONResponse(status_code=status_code, content=error)
+    return await call_next(request)
+
+# Rate limiting - every response carries the client's current window state
+@app.middleware("http")
+async def rate_limit_middleware(request: Request, call_next):
+    client = request.client.host if request.client else "unknown"
+    allowed, remaining, reset = rate_limiter.hit(client)
+    
+    if allowed:
+        response = await call_next(request)
+    else:
+        response = JSONResponse(
+            status_code=429,
+            content={"error": "Too many requests", "message": f"Rate limit of {rate_limiter.max_requests} requests exceeded"}
+        )
+    
+    response.headers["X-Rate-Limit-Limit"] = str(rate_limiter.max_requests)
+    response.headers["X-Rate-Limit-Remaining"] = str(remaining)
+    response.headers["X-Rate-Limit-Reset"] = str(reset)
+    return response
+
+# Traffic recording for regression replay (--replay); bodies above the size limit are not stored
+app.add_middleware(
+    TrafficRecordingMiddleware,
+    recorder_provider=lambda: traffic_recorder,
+    settings_provider=lambda: getattr(app.state, 'settings', settings),
+)
+
+# Security headers - wraps every other middleware except the access log, so it covers all routes
+SECURITY_HEADERS = {
+    "X-Content-Type-Options": "nosniff",
+    "X-Frame-Options": "DENY",
+    "X-XSS-Protection": "1; mode=block",
+    "Referrer-Policy": "no-referrer",
+}
+
+@app.middleware("http")
+async def security_headers_middleware(request: Request, call_next):
+    response = await call_next(request)
+    for header, value in SECURITY_HEADERS.items():
+        response.headers.setdefault(header, value)
+    
+    csp_header = getattr(request.app.state, 'settings', settings).csp_header
+    if csp_header:
+        response.headers.setdefault("Content-Security-Policy", csp_header)
+    return response
+
+# Optional access log - declared last so it is outermost and records every response, including rejections
+app.add_middleware(AccessLogMiddleware, logger_provider=lambda: access_logger)
+
 # Include API routers
 app.include_router(health.router, prefix="/api/v1", tags=["health"])
 app.include_router(models.router, prefix="/api/v1", tags=["models"])
 app.include_router(training.router, prefix="/api/v1", tags=["training"])
 app.include_router(plugins.router, prefix="/api/v1", tags=["plugins"])
 app.include_router(gpu.router, prefix="/api/v1", tags=["gpu"])
+app.include_router(profiles.router, prefix="/api/v1", tags=["profiles"])
+app.include_router(config.router, prefix="/api/v1", tags=["config"])
+app.include_router(features.router, prefix="/api/v1", tags=["features"])
+app.include_router(metrics.router, tags=["metrics"])  # /metrics, where Prometheus scrapes by default
+app.include_router(users.router, prefix="/api/v1", tags=["users"])
+app.include_router(diagnostics.router, prefix="/api/v1", tags=["diagnostics"])
+app.include_router(pipeline.router, prefix="/api/v1", tags=["pipeline"])
 
 # WebSocket endpoint for real-time updates
 @app.websocket("/ws")
@@ -169,6 +611,18 @@ async def root():
         "websocket": "/ws"
     }
 
+# Request validation - report every schema violation, not just the first
+@app.exception_handler(RequestValidationError)
+async def validation_exception_handler(request, exc):
+    return JSONResponse(
+        status_code=400,
+        content={
+            "error": "Invalid request",
+            "message": f"{len(exc.errors())} validation error(s)",
+            "violations": jsonable_encoder(exc.errors())
+        }
+    )
+
 # Global exception handler
 @app.exception_handler(Exception)
 async def global_exception_handler(request, exc):
@@ -182,31 +636,77 @@ async def global_exception_handler(request, exc):
         }
     )
 
+async def run_offline_diagnostics(cli_settings: Settings) -> Path:
+    """Run diagnostics without starting the server or initializing the GPU"""
+    offline_plugin_manager = PluginManager(cli_settings.plugin_directory, log_max_bytes=cli_settings.plugin_log_max_bytes)
+    await offline_plugin_manager.load_core_plugins()
+    try:
+        report = await run_diagnostics(plugin_manager=offline_plugin_manager)
+    finally:
+        await offline_plugin_manager.unload_all_plugins()
+    return write_diagnostics_report(report, cli_settings.diagnostics_directory)
+
 def main():
     """Main entry point"""
     parser = argparse.ArgumentParser(description="Archetype Neural Network Backend")
-    parser.add_argument("--host", default="localhost", help="Server host")
-    parser.add_argument("--port", type=int, default=8000, help="Server port")
+    parser.add_argument("--host", default=None, help="Server host")
+    parser.add_argument("--port", type=int, default=None, help="Server port")
     parser.add_argument("--reload", action="store_true", help="Enable auto-reload")
-    parser.add_argument("--log-level", default="info", help="Log level")
+    parser.add_argument("--log-level", default=None, help="Log level")
     parser.add_argument("--headless", action="store_true", help="Run in headless mode")
+    parser.add_argument("--print-config", action="store_true", help="Print the resolved configuration and exit")
+    parser.add_argument("--replay", type=Path, default=None, help="Replay a recorded traffic file against the running server and report differences")
+    parser.add_argument("--replay-speed", type=float, default=1.0, help="Replay pacing multiplier (2.0 = twice as fast, 0 = no delays)")
+    parser.add_argument("--run-diagnostics", action="store_true", help="Run the checks that need no GPU, write a report and exit")
     
     args = parser.parse_args()
     
+    # Headless servers initialize the GPU eagerly unless configured otherwise
+    if args.headless:
+        os.environ.setdefault("ARCHETYPE_LAZY_GPU_INIT", "false")
+    
+    # Resolve configuration: CLI flags > ARCHETYPE_* environment variables > .env file > defaults
+    cli_settings = Settings()
+    if args.host is not None:
+        cli_settings.host = args.host
+    if args.port is not None:
+        cli_settings.port = args.port
+    if args.log_level is not None:
+        cli_settings.log_level = args.log_level
+    
+    if args.print_config:
+        print(cli_settings.redacted_json())
+        return
+    
+    if args.replay:
+        report = replay_traffic(args.replay, f"http://{cli_settings.host}:{cli_settings.port}",
+                                args.replay_speed, cli_settings.admin_api_key)
+        print(json.dumps(report, indent=2))
+        return
+    
+    if args.run_diagnostics:
+        setup_logging(level=cli_settings.log_level.upper())
+        print(asyncio.run(run_offline_diagnostics(cli_settings)))
+        return
+    
     # Setup logging
-    setup_logging(level=args.log_level.upper())
+    setup_logging(level=cli_settings.log_level.upper())
     
-    logging.info(f"🐍 Starting Archetype Backend on {args.host}:{args.port}")
+    logging.info(f"🐍 Starting Archetype Backend on {cli_settings.host}:{cli_settings.port}")
     
     # Run server
-    uvicorn.run(
-        "main:app",
-        host=args.host,
-        port=args.port,
-        reload=args.reload,
-        log_level=args.log_level,
-        access_log=not args.headless
+    server_options = dict(
+        host=cli_settings.host,
+        port=cli_settings.port,
+        log_level=cli_settings.log_level,
+        access_log=not args.headless,
+        timeout_graceful_shutdown=cli_settings.drain_timeout_ms / 1000
     )
+    if args.reload:
+        # The reloader needs an import string and supervises its own server processes
+        uvicorn.run("main:app", reload=True, **server_options)
+    else:
+        ArchetypeServer(uvicorn.Config(app, **server_options)).run()
 
 if __name__ == "__main__":
     main()
\ No newline at end of file
@@ -1,7 +1,7 @@
 from fastapi import APIRouter
 
 # Import all endpoint routers for easy access
-from .endpoints import health, models, training, plugins, gpu
+from .endpoints import health, models, training, plugins, gpu, profiles, config, features, metrics, users, diagnostics
 
 # Create main API router
 api_router = APIRouter()
@@ -12,6 +12,12 @@ api_router.include_router(models.router, prefix="/models", tags=["models"])
 api_router.include_router(training.router, prefix="/training", tags=["training"])
 api_router.include_router(plugins.router, prefix="/plugins", tags=["plugins"])
 api_router.include_router(gpu.router, prefix="/gpu", tags=["gpu"])
+api_router.include_router(profiles.router, prefix="/profiles", tags=["profiles"])
+api_router.include_router(config.router, prefix="/config", tags=["config"])
+api_router.include_router(features.router, prefix="/features", tags=["features"])
+api_router.include_router(metrics.router, tags=["metrics"])
+api_router.include_router(users.router, prefix="/users", tags=["users"])
+api_router.include_router(diagnostics.router, prefix="/diagnostics", tags=["diagnostics"])
 
 __all__ = [
     "api_router",
@@ -19,5 +25,11 @@ __all__ = [
     "models", 
     "training",
     "plugins",
-    "gpu"
+    "gpu",
+    "profiles",
+    "config",
+    "features",
+    "metrics",
+    "users",
+    "diagnostics"
 ]
\ No newline at end of file
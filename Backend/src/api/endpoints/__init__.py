@@ -4,11 +4,25 @@ from . import models
 from . import training
 from . import plugins
 from . import gpu
+from . import profiles
+from . import config
+from . import features
+from . import metrics
+from . import users
+from . import diagnostics
+from . import pipeline
 
 __all__ = [
     "health",
     "models",
     "training", 
     "plugins",
-    "gpu"
+    "gpu",
+    "profiles",
+    "config",
+    "features",
+    "metrics",
+    "users",
+    "diagnostics",
+    "pipeline"
 ]
\ No newline at end of file
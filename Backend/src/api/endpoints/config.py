@@ -0,0 +1,26 @@
+from fastapi import APIRouter, Request, HTTPException
+
+from src.utils.config_reload import reload_settings
+
+router = APIRouter()
+
+@router.get("/config/environment")
+async def get_environment(request: Request):
+    """Get the deployment environment the backend is running in"""
+    settings = getattr(request.app.state, 'settings', None)
+    if not settings:
+        raise HTTPException(status_code=503, detail="Backend not initialized")
+    
+    return {"environment": settings.environment}
+
+@router.post("/config/reload")
+async def reload_config(request: Request):
+    """Reload configuration without restarting (equivalent to SIGHUP)"""
+    if not getattr(request.app.state, 'settings', None):
+        raise HTTPException(status_code=503, detail="Backend not initialized")
+    
+    try:
+        result = reload_settings(request.app.state)
+        return {"status": "reloaded", **result}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
@@ -0,0 +1,21 @@
+from fastapi import APIRouter, Request, HTTPException
+from dataclasses import asdict
+
+from src.utils.diagnostics import run_diagnostics, write_diagnostics_report
+
+router = APIRouter()
+
+@router.post("/diagnostics")
+async def run_backend_diagnostics(request: Request):
+    """Run every diagnostic check and save the report for attaching to a bug"""
+    settings = getattr(request.app.state, 'settings', None)
+    if not settings:
+        raise HTTPException(status_code=503, detail="Backend not initialized")
+    
+    report = await run_diagnostics(
+        getattr(request.app.state, 'gpu_manager', None),
+        getattr(request.app.state, 'plugin_manager', None),
+        getattr(request.app.state, 'circuit_breakers', None)
+    )
+    report_path = write_diagnostics_report(report, settings.diagnostics_directory)
+    return {"report_path": str(report_path), **asdict(report)}
@@ -0,0 +1,52 @@
+from fastapi import APIRouter, Request, HTTPException
+import secrets
+
+router = APIRouter()
+
+def require_admin_api_key(request: Request):
+    """Reject the request unless X-API-Key matches the configured admin_api_key"""
+    admin_api_key = request.app.state.settings.admin_api_key
+    if not admin_api_key:
+        raise HTTPException(status_code=403, detail="Operation not allowed: admin_api_key is not configured")
+    if not secrets.compare_digest(request.headers.get("x-api-key", ""), admin_api_key):
+        raise HTTPException(status_code=401, detail="Invalid or missing X-API-Key")
+
+@router.get("/features")
+async def list_feature_flags(request: Request):
+    """Get the enabled state of each feature"""
+    feature_flags = getattr(request.app.state, 'feature_flags', None)
+    if not feature_flags:
+        raise HTTPException(status_code=503, detail="Feature flags not initialized")
+    
+    return {"features": dict(feature_flags.flags)}
+
+@router.post("/features/{feature}")
+async def set_feature_flag(feature: str, enabled: bool, request: Request):
+    """Enable or disable a feature at runtime (requires the admin API key)"""
+    feature_flags = getattr(request.app.state, 'feature_flags', None)
+    if not feature_flags:
+        raise HTTPException(status_code=503, detail="Feature flags not initialized")
+    
+    require_admin_api_key(request)
+    
+    try:
+        client = request.client.host if request.client else "unknown"
+        feature_flags.set_flag(feature, enabled, changed_by=client)
+        return {"feature": feature, "enabled": enabled}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/features/circuit-breakers/{subsystem}/reset")
+async def reset_circuit_breaker(subsystem: str, request: Request):
+    """Force a subsystem's circuit breaker closed (requires the admin API key)"""
+    circuit_breakers = getattr(request.app.state, 'circuit_breakers', None)
+    if circuit_breakers is None:
+        raise HTTPException(status_code=503, detail="Circuit breakers not initialized")
+    
+    require_admin_api_key(request)
+    
+    if subsystem not in circuit_breakers:
+        raise HTTPException(status_code=404, detail=f"Unknown subsystem: {subsystem}")
+    
+    circuit_breakers[subsystem].reset()
+    return {"subsystem": subsystem, "state": circuit_breakers[subsystem].state.value}
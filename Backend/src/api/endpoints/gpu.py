@@ -1,139 +1,10 @@
-from fastapi import APIRouter, Request, HTTPException
+from fastapi import APIRouter, Request, HTTPException, Query
 from pydantic import BaseModel
 from typing import List, Dict, Any
-import torch as torch
-import time as time
-import psutil
+from dataclasses import asdict
 
-router = APIRouter()
-
-class GPUInfo(BaseModel):
-    device_id: int
-    name: str
-    compute_capability: str
-    total_memory: int
-    available_memory: int
-    utilization: float
-    temperature: float
-    power_usage: float
-
-class GPUListResponse(BaseModel):
-    gpus: List[GPUInfo]
-    selected_gpu: int
-    cuda_available: bool
-    total_devices: int
-
-@router.get("/gpu", response_model=GPUListResponse)
-async def get_gpu_info(request: Request):
-    """Get GPU information"""
-    gpu_manager = request.app.state.gpu_manager
-    
-    try:
-        gpu_info = await gpu_manager.get_gpu_info()
-        return GPUListResponse(**gpu_info)
-    except Exception as e:
-        raise HTTPException(status_code=500, detail=str(e))
-
-@router.get("/gpu/{device_id}", response_model=GPUInfo)
-async def get_specific_gpu(device_id: int, request: Request):
-    """Get specific GPU information"""
-    gpu_manager = request.app.state.gpu_manager
-    
-    try:
-        gpu_info = await gpu_manager.get_device_info(device_id)
-        return GPUInfo(**gpu_info)
-    except Exception as e:
-        raise HTTPException(status_code=404, detail=str(e))
-
-@router.post("/gpu/{device_id}/select")
-async def select_gpu(device_id: int, request: Request):
-    """Select GPU for training"""
-    gpu_manager = request.app.state.gpu_manager
-    
-    try:
-        await gpu_manager.set_device(device_id)
-        return {"status": "selected", "device_id": device_id}
-    except Exception as e:
-        raise HTTPException(status_code=400, detail=str(e))
-
-@router.get("/gpu/memory/clear")
-async def clear_gpu_memory(request: Request):
-    """Clear GPU memory cache"""
-    gpu_manager = request.app.state.gpu_manager
-    
-    try:
-        await gpu_manager.clear_cache()
-        return {"status": "cleared"}
-    except Exception as e:
-        raise HTTPException(status_code=500, detail=str(e))
-
-@router.get("/gpu/benchmark")
-async def benchmark_gpu(request: Request):
-    """Run GPU benchmark"""
-    gpu_manager = request.app.state.gpu_manager
-    
-    try:
-        benchmark_results = await gpu_manager.run_benchmark()
-        return benchmark_results
-    except Exception as e:
-        raise HTTPException(status_code=500, detail=str(e))
-        router.get("/health", response_model=HealthResponse) 
-      
-async def health_check(request: Request):
-    """Health check endpoint for Unity connection verification"""
-    current_time = time.time()
-    startup_time = request.app.state.startup_time
-    uptime = current_time - startup_time
-    
-    # Get system metrics
-    memory_usage = psutil.virtual_memory().percent
-    cpu_usage = psutil.cpu_percent(interval=0.1)
-    
-    # GPU information
-    gpu_available = torch.cuda.is_available()
-    gpu_count = torch.cuda.device_count() if gpu_available else 0
-    
-    return {
-        "status": "healthy",
-        "version": "1.0.0",
-        "uptime": uptime,
-        "timestamp": time.strftime("%Y-%m-%d %H:%M:%S"),
-        "gpu_available": gpu_available,
-        "gpu_count": gpu_count,
-        "memory_usage_percent": memory_usage,
-        "cpu_usage_percent": cpu_usage
-    }
-
-@router.get("/health/detailed")
-async def detailed_health_check(request: Request):
-    """Detailed health check with component status"""
-    gpu_manager = request.app.state.gpu_manager
-    model_factory = request.app.state.model_factory
-    training_engine = request.app.state.training_engine
-    plugin_manager = request.app.state.plugin_manager
-    
-    return {
-        "status": "healthy",
-        "components": {
-            "gpu_manager": {
-                "initialized": gpu_manager.is_initialized(),
-                "device": str(gpu_manager.device) if gpu_manager.is_initialized() else None,
-                "memory_allocated": torch.cuda.memory_allocated() if torch.cuda.is_available() else 0
-            },
-            "model_factory": {
-                "initialized": model_factory.is_initialized(),
-                "active_models": len(model_factory.get_active_models())
-            },
-            "training_engine": {
-                "initialized": training_engine.is_initialized(),
-                "active_trainings": len(training_engine.get_active_trainings())
-            },
-            "plugin_manager": {
-                "loaded_plugins": len(plugin_manager.get_loaded_plugins()),
-                "core_plugins": len(plugin_manager.get_core_plugins())
-            }
-        }
-    }
+from src.core.gpu_manager import DeviceCriteria, MAX_BANDWIDTH_TEST_BYTES
+from src.utils.etag import etag_response
 
 router = APIRouter()
 
@@ -155,6 +26,29 @@ class GPUSettings(BaseModel):
     available_preferences: List[GPUPreference]
     current_preference: str
 
+class GPUListResponse(BaseModel):
+    devices: List[Dict[str, Any]]
+    selected_device: str
+    total_devices: int
+
+@router.get("/gpu", response_model=GPUListResponse)
+async def get_gpu_info(request: Request):
+    """List detected devices; sends an ETag so pollers get 304 Not Modified until the devices or selection change"""
+    gpu_manager = getattr(request.app.state, 'gpu_manager', None)
+    if not gpu_manager:
+        raise HTTPException(status_code=503, detail="GPU manager not initialized")
+    
+    try:
+        await gpu_manager.ensure_initialized()
+        devices = gpu_manager.get_all_devices()
+        return etag_response(request, GPUListResponse(
+            devices=devices,
+            selected_device=gpu_manager.get_device_info(),
+            total_devices=len(devices)
+        ))
+    except Exception as e:
+        raise HTTPException(status_code=500, detail=f"Failed to get GPU info: {str(e)}")
+
 @router.get("/gpu/settings", response_model=GPUSettings)
 async def get_gpu_settings(request: Request):
     """Get current GPU settings and available options"""
@@ -163,6 +57,8 @@ async def get_gpu_settings(request: Request):
         raise HTTPException(status_code=503, detail="GPU manager not initialized")
     
     try:
+        await gpu_manager.ensure_initialized()
+        
         # Get current device info
         current_device = {}
         if gpu_manager.selected_device:
@@ -188,12 +84,12 @@ async def get_gpu_settings(request: Request):
         # Get current preference
         current_preference = gpu_manager.get_current_preference()
         
-        return GPUSettings(
+        return etag_response(request, GPUSettings(
             current_device=current_device,
             available_devices=available_devices,
             available_preferences=available_preferences,
             current_preference=current_preference
-        )
+        ))
         
     except Exception as e:
         raise HTTPException(status_code=500, detail=f"Failed to get GPU settings: {str(e)}")
@@ -251,6 +147,21 @@ async def run_gpu_benchmark(request: Request):
     except Exception as e:
         raise HTTPException(status_code=500, detail=f"Benchmark failed: {str(e)}")
 
+@router.post("/gpu/bandwidth")
+async def measure_gpu_bandwidth(request: Request,
+                                size_bytes: int = Query(256 * 1024 * 1024, gt=0, le=MAX_BANDWIDTH_TEST_BYTES)):
+    """Measure host/device transfer bandwidth against the detected PCIe link"""
+    gpu_manager = getattr(request.app.state, 'gpu_manager', None)
+    if not gpu_manager:
+        raise HTTPException(status_code=503, detail="GPU manager not initialized")
+    
+    try:
+        return asdict(await gpu_manager.measure_memory_bandwidth(size_bytes))
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e))
+    except Exception as e:
+        raise HTTPException(status_code=500, detail=f"Bandwidth measurement failed: {str(e)}")
+
 @router.post("/gpu/optimize-for-model")
 async def optimize_for_model(request: Request, model_size_mb: int):
     """Get optimization recommendations for a specific model size"""
@@ -262,4 +173,43 @@ async def optimize_for_model(request: Request, model_size_mb: int):
         optimization = await gpu_manager.optimize_for_model_size(model_size_mb)
         return optimization
     except Exception as e:
-        raise HTTPException(status_code=500, detail=f"Optimization failed: {str(e)}")
\ No newline at end of file
+        raise HTTPException(status_code=500, detail=f"Optimization failed: {str(e)}")
+
+class DeviceCriteriaRequest(BaseModel):
+    vram_weight: float = 1.0
+    compute_weight: float = 1.0
+    feature_weight: float = 1.0
+    prefer_discrete: bool = True
+    discrete_bonus: float = 0.25
+    required_features: List[str] = []
+
+@router.post("/gpu/rank-devices")
+async def rank_gpu_devices(request: Request, criteria: DeviceCriteriaRequest):
+    """Rank detected devices by a weighted score for the device selection UI"""
+    gpu_manager = getattr(request.app.state, 'gpu_manager', None)
+    if not gpu_manager:
+        raise HTTPException(status_code=503, detail="GPU manager not initialized")
+    
+    try:
+        await gpu_manager.ensure_initialized()
+        scores = gpu_manager.get_adapter_score(DeviceCriteria(**criteria.model_dump()))
+        devices = gpu_manager.get_all_devices()
+        
+        return {
+            "ranked_devices": [
+                {"index": index, "score": score, "device": devices[index]}
+                for index, score in scores
+            ]
+        }
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/gpu/initialize")
+async def initialize_gpu(request: Request):
+    """Initialize the GPU now instead of waiting for first use"""
+    gpu_manager = getattr(request.app.state, 'gpu_manager', None)
+    if not gpu_manager:
+        raise HTTPException(status_code=503, detail="GPU manager not initialized")
+    
+    await gpu_manager.ensure_initialized()
+    return {"status": "initialized", "selected_device": gpu_manager.get_device_info()}
@@ -1,9 +1,13 @@
 from fastapi import APIRouter, Request, HTTPException
+from fastapi.responses import JSONResponse
 from pydantic import BaseModel
+from typing import Dict, Optional
 import time
 import psutil
 import torch
 
+from src.utils.health_state import HealthState
+
 router = APIRouter()
 
 class HealthResponse(BaseModel):
@@ -15,6 +19,9 @@ class HealthResponse(BaseModel):
     gpu_count: int
     memory_usage_percent: float
     cpu_usage_percent: float
+    active_profile: Optional[str] = None
+    startup_timings_ms: Dict[str, float] = {}
+    circuit_breakers: Dict[str, str] = {}
 
 @router.get("/health", response_model=HealthResponse)
 async def health_check(request: Request):
@@ -32,6 +39,11 @@ async def health_check(request: Request):
         gpu_available = torch.cuda.is_available()
         gpu_count = torch.cuda.device_count() if gpu_available else 0
         
+        profile_manager = getattr(request.app.state, 'profile_manager', None)
+        active_profile = profile_manager.active_profile if profile_manager else None
+        startup_timings = getattr(request.app.state, 'startup_timings', {})
+        circuit_breakers = getattr(request.app.state, 'circuit_breakers', {})
+        
         return HealthResponse(
             status="healthy",
             version="1.0.0",
@@ -40,7 +52,10 @@ async def health_check(request: Request):
             gpu_available=gpu_available,
             gpu_count=gpu_count,
             memory_usage_percent=memory_usage,
-            cpu_usage_percent=cpu_usage
+            cpu_usage_percent=cpu_usage,
+            active_profile=active_profile,
+            startup_timings_ms=dict(startup_timings),
+            circuit_breakers={name: breaker.state.value for name, breaker in circuit_breakers.items()}
         )
     except Exception as e:
         # Return a minimal healthy response even if some metrics fail
@@ -63,9 +78,11 @@ async def detailed_health_check(request: Request):
         model_factory = getattr(request.app.state, 'model_factory', None)
         training_engine = getattr(request.app.state, 'training_engine', None)
         plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+        inflight_tracker = getattr(request.app.state, 'inflight_tracker', None)
         
         return {
             "status": "healthy",
+            "inflight_requests": inflight_tracker.count if inflight_tracker else 0,
             "components": {
                 "gpu_manager": {
                     "initialized": gpu_manager.is_initialized() if gpu_manager else False,
@@ -87,4 +104,41 @@ async def detailed_health_check(request: Request):
             }
         }
     except Exception as e:
-        raise HTTPException(status_code=500, detail=f"Health check failed: {str(e)}")
\ No newline at end of file
+        raise HTTPException(status_code=500, detail=f"Health check failed: {str(e)}")
+
+@router.get("/health/startup")
+async def startup_probe(request: Request):
+    """Startup probe - 503 while initializing, 404 once the startup sequence is complete"""
+    state = getattr(request.app.state, 'health_state', HealthState.STARTING)
+    
+    if state in (HealthState.STARTING, HealthState.FAILED):
+        return JSONResponse(status_code=503, content={"state": state.value})
+    if state == HealthState.LIVE:
+        return {"state": state.value}
+    raise HTTPException(status_code=404, detail="Startup sequence complete")
+
+@router.get("/health/live")
+async def liveness_probe(request: Request):
+    """Liveness probe - 200 unless the backend hit a fatal error, including while startup is still running"""
+    state = getattr(request.app.state, 'health_state', HealthState.STARTING)
+    
+    if state == HealthState.FAILED:
+        return JSONResponse(status_code=503, content={"state": state.value})
+    return {"state": state.value}
+
+@router.get("/health/ready")
+async def readiness_probe(request: Request):
+    """Readiness probe - 200 only once startup is complete and every readiness check passes"""
+    state = getattr(request.app.state, 'health_state', HealthState.STARTING)
+    
+    if state not in (HealthState.READY, HealthState.DEGRADED):
+        return JSONResponse(status_code=503, content={"state": state.value, "failing_checks": []})
+    
+    # Reports the checks as they stand now; the READY/DEGRADED transition is made by the startup sequence
+    readiness_checks = getattr(request.app.state, 'readiness_checks', None)
+    ready, failing_checks = readiness_checks.evaluate() if readiness_checks else (state == HealthState.READY, [])
+    reported = HealthState.READY if ready else HealthState.DEGRADED
+    
+    if not ready:
+        return JSONResponse(status_code=503, content={"state": reported.value, "failing_checks": failing_checks})
+    return {"state": reported.value, "failing_checks": []}
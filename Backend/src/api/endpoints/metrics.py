@@ -0,0 +1,18 @@
+from fastapi import APIRouter, Request, HTTPException
+from fastapi.responses import PlainTextResponse
+
+router = APIRouter()
+
+OPENMETRICS_CONTENT_TYPE = "application/openmetrics-text; version=1.0.0; charset=utf-8"
+PROMETHEUS_CONTENT_TYPE = "text/plain; version=0.0.4; charset=utf-8"
+
+@router.get("/metrics")
+async def get_metrics(request: Request):
+    """Metrics for Prometheus scraping; OpenMetrics when the client accepts it"""
+    metrics_registry = getattr(request.app.state, 'metrics_registry', None)
+    if not metrics_registry:
+        raise HTTPException(status_code=503, detail="Metrics not initialized")
+    
+    if "application/openmetrics-text" in request.headers.get("accept", ""):
+        return PlainTextResponse(metrics_registry.export_openmetrics(), media_type=OPENMETRICS_CONTENT_TYPE)
+    return PlainTextResponse(metrics_registry.export_prometheus(), media_type=PROMETHEUS_CONTENT_TYPE)
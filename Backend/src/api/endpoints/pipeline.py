@@ -0,0 +1,149 @@
+from fastapi import APIRouter, Request, HTTPException
+from pydantic import BaseModel
+from typing import List, Dict, Any, Optional, Tuple
+import posixpath
+import re
+from urllib.parse import quote, unquote
+
+import httpx
+
+router = APIRouter()
+
+MAX_PIPELINE_STEPS = 50
+PIPELINE_METHODS = ("GET", "POST", "PUT", "DELETE")
+PIPELINE_PATH_PREFIX = "/api/v1/"
+FORWARDED_HEADERS = ("x-api-key", "authorization", "x-request-id")
+PATH_PARAMETER_PATTERN = re.compile(r"\{([A-Za-z_][A-Za-z0-9_]*)\}")
+
+class PipelineStep(BaseModel):
+    command: str  # "METHOD /api/v1/route", e.g. "POST /api/v1/plugins/{plugin_id}/load"
+    id: Optional[str] = None  # Defaults to the step's index
+    parameters: Dict[str, Any] = {}
+    query: Dict[str, Any] = {}
+    output_mappings: Dict[str, str] = {}  # Dotted path in this step's output -> "<step id>.<dotted parameter path>"
+    continue_on_error: bool = False
+
+class PipelineRequest(BaseModel):
+    steps: List[PipelineStep]
+
+def check_target(path: str):
+    """Check a path names an API route other than the pipeline itself, after decoding and resolving dot segments"""
+    normalized = posixpath.normpath(unquote(path))
+    if not normalized.startswith(PIPELINE_PATH_PREFIX):
+        raise ValueError(f"Path {path!r} must target a route under {PIPELINE_PATH_PREFIX}")
+    if normalized == "/api/v1/pipeline":
+        raise ValueError("A pipeline cannot run another pipeline")
+
+def parse_command(command: str) -> Tuple[str, str]:
+    """Split "METHOD /path" and check the path"""
+    method, _, path = command.strip().partition(" ")
+    method, path = method.upper(), path.strip()
+
+    if method not in PIPELINE_METHODS:
+        raise ValueError(f"Unsupported method in command {command!r}; use one of {', '.join(PIPELINE_METHODS)}")
+    check_target(path)
+    return method, path
+
+def validate_pipeline(steps: List[PipelineStep]) -> List[str]:
+    """Check every command and mapping before anything runs; returns the step ids in order"""
+    if not steps:
+        raise ValueError("A pipeline needs at least one step")
+    if len(steps) > MAX_PIPELINE_STEPS:
+        raise ValueError(f"A pipeline can have at most {MAX_PIPELINE_STEPS} steps")
+
+    step_ids = [step.id or str(index) for index, step in enumerate(steps)]
+    if len(set(step_ids)) != len(step_ids):
+        raise ValueError("Step ids must be unique")
+
+    positions = {step_id: index for index, step_id in enumerate(step_ids)}
+    for index, step in enumerate(steps):
+        parse_command(step.command)
+        for source, target in step.output_mappings.items():
+            target_id, _, parameter = target.partition(".")
+            if target_id not in positions or not parameter or not source:
+                raise ValueError(f"Step {step_ids[index]}: mapping {source!r} -> {target!r} must target '<step id>.<parameter>'")
+            # Steps run in order, so a mapping into this step or an earlier one could only be satisfied by a cycle
+            if positions[target_id] <= index:
+                raise ValueError(f"Step {step_ids[index]}: mapping {source!r} -> {target!r} is circular")
+    return step_ids
+
+def get_dotted(data: Any, path: str) -> Any:
+    """Read a dotted path such as "devices.0.id" from decoded JSON"""
+    for key in path.split("."):
+        if isinstance(data, list) and key.isdigit() and int(key) < len(data):
+            data = data[int(key)]
+        elif isinstance(data, dict) and key in data:
+            data = data[key]
+        else:
+            raise KeyError(path)
+    return data
+
+def set_dotted(data: Dict[str, Any], path: str, value: Any):
+    """Write value at a dotted path, creating intermediate objects"""
+    *parents, last = path.split(".")
+    for key in parents:
+        data = data.setdefault(key, {})
+    data[last] = value
+
+def build_request(method: str, path: str, parameters: Dict[str, Any], query: Dict[str, Any]) -> Dict[str, Any]:
+    """Fill path placeholders from parameters; the rest go in the query string (GET/DELETE) or the JSON body"""
+    remaining = dict(parameters)
+    missing = [name for name in PATH_PARAMETER_PATTERN.findall(path) if name not in remaining]
+    if missing:
+        raise ValueError(f"Missing path parameters: {', '.join(missing)}")
+    # Values may come from earlier steps' output, so they are encoded and cannot add path segments or a query
+    url = PATH_PARAMETER_PATTERN.sub(lambda match: quote(str(remaining.pop(match.group(1))), safe=""), path)
+    check_target(url)
+
+    if method in ("GET", "DELETE"):
+        return {"method": method, "url": url, "params": {**remaining, **query}}
+    return {"method": method, "url": url, "params": query, "json": remaining or None}
+
+@router.post("/pipeline")
+async def run_pipeline(pipeline: PipelineRequest, request: Request):
+    """Run API commands in sequence in one round-trip, feeding mapped output fields into later steps' parameters"""
+    try:
+        step_ids = validate_pipeline(pipeline.steps)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+    # Steps go through the full middleware stack as the caller, so access control and rate limits still apply
+    headers = {name: request.headers[name] for name in FORWARDED_HEADERS if name in request.headers}
+    client = (request.client.host, request.client.port) if request.client else ("127.0.0.1", 0)
+    transport = httpx.ASGITransport(app=request.app, client=client)
+
+    parameters = {step_id: dict(step.parameters) for step_id, step in zip(step_ids, pipeline.steps)}
+    results = []
+    completed = True
+
+    async with httpx.AsyncClient(transport=transport, base_url="http://pipeline", headers=headers) as http:
+        for step_id, step in zip(step_ids, pipeline.steps):
+            result = {"id": step_id, "command": step.command, "status_code": None, "ok": False, "output": None, "error": None}
+            results.append(result)
+
+            try:
+                method, path = parse_command(step.command)
+                response = await http.request(**build_request(method, path, parameters[step_id], step.query))
+                result["status_code"] = response.status_code
+                try:
+                    result["output"] = response.json() if response.content else None
+                except ValueError:
+                    result["output"] = response.text
+
+                if not response.is_success:
+                    raise RuntimeError(f"{step.command} returned {response.status_code}")
+
+                for source, target in step.output_mappings.items():
+                    target_id, _, parameter = target.partition(".")
+                    try:
+                        set_dotted(parameters[target_id], parameter, get_dotted(result["output"], source))
+                    except KeyError:
+                        raise RuntimeError(f"Output field {source!r} not found for mapping to {target!r}")
+                result["ok"] = True
+            except Exception as e:
+                result["error"] = str(e)
+                if not step.continue_on_error:
+                    completed = False
+                    break
+
+    return {"completed": completed, "results": results}
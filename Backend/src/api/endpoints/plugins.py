@@ -1,8 +1,23 @@
-from fastapi import APIRouter, Request, HTTPException
+from fastapi import APIRouter, Depends, Request, Response, HTTPException
 from pydantic import BaseModel
-from typing import List, Dict, Any
+from typing import List, Dict, Any, Optional
+from dataclasses import asdict
 
-router = APIRouter()
+from src.utils.etag import etag_response
+from src.plugins.plugin_manager import PluginDependentsLoaded, PluginInABTest, CapabilityRemovalRequiresReload, is_legacy_plugin_id
+
+def warn_on_legacy_plugin_id(request: Request, response: Response):
+    """Flag requests naming an extension plugin by a deprecated un-namespaced ID"""
+    plugin_id = request.path_params.get("plugin_id")
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_id or not is_legacy_plugin_id(plugin_id):
+        return
+    if plugin_manager and plugin_id in plugin_manager.core_plugin_modules:
+        return
+    response.headers["Deprecation"] = "true"
+    response.headers["Warning"] = f'299 - "Un-namespaced plugin id {plugin_id} is deprecated; use vendor.plugin_name"'
+
+router = APIRouter(dependencies=[Depends(warn_on_legacy_plugin_id)])
 
 class PluginInfo(BaseModel):
     id: str
@@ -13,9 +28,18 @@ class PluginInfo(BaseModel):
     plugin_type: str
     loaded: bool
     enabled: bool
+    paused: bool = False
+    lazy_pending: bool = False
+    sha256_hash: str = ""
+    available_rollback_versions: List[str] = []
+    is_clone: bool = False
+    cloned_from: Optional[str] = None
     dependencies: List[str]
     manifest: Dict[str, Any]
 
+class CapabilityUpgradeRequest(BaseModel):
+    capabilities: List[str]
+
 class PluginListResponse(BaseModel):
     plugins: List[PluginInfo]
     total: int
@@ -26,7 +50,7 @@ async def list_plugins(request: Request):
     plugin_manager = request.app.state.plugin_manager
     
     plugins = await plugin_manager.list_plugins()
-    return PluginListResponse(plugins=plugins, total=len(plugins))
+    return etag_response(request, PluginListResponse(plugins=plugins, total=len(plugins)))
 
 @router.get("/plugins/{plugin_id}", response_model=PluginInfo)
 async def get_plugin(plugin_id: str, request: Request):
@@ -51,15 +75,31 @@ async def load_plugin(plugin_id: str, request: Request):
         raise HTTPException(status_code=400, detail=str(e))
 
 @router.post("/plugins/{plugin_id}/unload")
-async def unload_plugin(plugin_id: str, request: Request):
-    """Unload a plugin"""
+async def unload_plugin(plugin_id: str, request: Request, force: bool = False):
+    """Unload a plugin; pass force to unload it even while other plugins depend on it"""
     plugin_manager = request.app.state.plugin_manager
     
     try:
-        await plugin_manager.unload_plugin(plugin_id)
+        await plugin_manager.unload_plugin(plugin_id, force=force)
         return {"status": "unloaded", "plugin_id": plugin_id}
+    except PluginDependentsLoaded as e:
+        raise HTTPException(status_code=409, detail={"error": "DependentsLoaded", "message": str(e), "dependents": e.dependents})
+    except PluginInABTest as e:
+        raise HTTPException(status_code=409, detail={"error": "InABTest", "message": str(e), "ab_test": e.test_plugin_id})
     except Exception as e:
         raise HTTPException(status_code=400, detail=str(e))
+
+@router.get("/plugins/{plugin_id}/dependents")
+async def get_plugin_dependents(plugin_id: str, request: Request):
+    """List loaded plugins that would break if this plugin were unloaded"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        return {"plugin_id": plugin_id, "dependents": plugin_manager.get_loaded_plugin_dependency_closure(plugin_id)}
+    except Exception as e:
+        raise HTTPException(status_code=404, detail=str(e))
     
 @router.post("/plugins/{plugin_id}/enable")
 async def enable_plugin(plugin_id: str, request: Request):
@@ -87,6 +127,329 @@ async def disable_plugin(plugin_id: str, request: Request):
     except Exception as e:
         raise HTTPException(status_code=400, detail=str(e))
 
+@router.get("/plugins/{plugin_id}/log")
+async def get_plugin_log(plugin_id: str, request: Request, lines: int = 100):
+    """Get the last lines of a plugin's log file"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        log_lines = plugin_manager.get_plugin_log(plugin_id, lines)
+        return {"plugin_id": plugin_id, "lines": log_lines}
+    except Exception as e:
+        raise HTTPException(status_code=404, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/dry-run")
+async def dry_run_load_plugin(plugin_id: str, request: Request):
+    """Validate that a plugin would load, without loading it"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        report = await plugin_manager.dry_run_load(plugin_id)
+        return asdict(report)
+    except Exception as e:
+        raise HTTPException(status_code=404, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/self-test")
+async def run_plugin_self_test(plugin_id: str, request: Request):
+    """Run a loaded plugin's self-test"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        report = await plugin_manager.run_plugin_self_test(plugin_id)
+        return asdict(report)
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/reload")
+async def reload_plugin(plugin_id: str, request: Request):
+    """Reload a plugin from disk, rolling back automatically if the new version is unhealthy"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        return {"plugin_id": plugin_id, **await plugin_manager.reload_plugin(plugin_id)}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/rollback")
+async def rollback_plugin(plugin_id: str, request: Request, steps_back: int = 1):
+    """Restore a previous version of a plugin"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        restored_hash = await plugin_manager.rollback_plugin(plugin_id, steps_back)
+        return {"message": f"Plugin {plugin_id} rolled back", "sha256_hash": restored_hash}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/capabilities")
+async def upgrade_plugin_capabilities(plugin_id: str, upgrade: CapabilityUpgradeRequest, request: Request):
+    """Add call graph capabilities to a loaded plugin without reloading it"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        return {"plugin_id": plugin_id, "call_graph": plugin_manager.upgrade_plugin_capabilities(plugin_id, upgrade.capabilities)}
+    except CapabilityRemovalRequiresReload as e:
+        raise HTTPException(status_code=409, detail=str(e))
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.get("/plugins/{plugin_id}/tags")
+async def list_plugin_tags(plugin_id: str, request: Request):
+    """List a plugin's release tags and the versions they point to"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    return {"plugin_id": plugin_id, "tags": plugin_manager.list_plugin_tags(plugin_id)}
+
+@router.post("/plugins/{plugin_id}/tags/{tag}")
+async def tag_plugin_version(plugin_id: str, tag: str, request: Request, sha256_hash: Optional[str] = None):
+    """Tag the loaded version of a plugin, or a version from its history"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        tagged_hash = plugin_manager.tag_plugin_version(plugin_id, tag, sha256_hash)
+        return {"message": f"Plugin {plugin_id} tagged {tag}", "sha256_hash": tagged_hash}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/tags/{tag}/load")
+async def load_plugin_by_tag(plugin_id: str, tag: str, request: Request):
+    """Restore the plugin version a tag points to"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        restored_hash = await plugin_manager.load_plugin_by_tag(plugin_id, tag)
+        return {"message": f"Plugin {plugin_id} loaded at tag {tag}", "sha256_hash": restored_hash}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/clone")
+async def clone_plugin_state(plugin_id: str, new_id: str, request: Request):
+    """Clone a loaded plugin's state into a new, independent plugin instance"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        await plugin_manager.clone_plugin_state(plugin_id, new_id)
+        return {"message": f"Plugin {plugin_id} cloned as {new_id}"}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.get("/plugins/{plugin_id}/api")
+async def describe_plugin_api(plugin_id: str, request: Request):
+    """Describe a loaded plugin's components and their parameter schemas"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        return plugin_manager.describe_plugin_api(plugin_id)
+    except Exception as e:
+        raise HTTPException(status_code=404, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/ab-test")
+async def enable_ab_test(plugin_id: str, variant_id: str, traffic_split: float, request: Request):
+    """Route a fraction of a plugin's calls to a variant plugin"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        await plugin_manager.enable_ab_test(plugin_id, variant_id, traffic_split)
+        return {"message": f"A/B test started on {plugin_id}"}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.get("/plugins/{plugin_id}/ab-test")
+async def get_ab_test_report(plugin_id: str, request: Request):
+    """Get per-arm statistics for a plugin's A/B test"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        return plugin_manager.get_ab_test_report(plugin_id)
+    except Exception as e:
+        raise HTTPException(status_code=404, detail=str(e))
+
+@router.delete("/plugins/{plugin_id}/ab-test")
+async def disable_ab_test(plugin_id: str, request: Request):
+    """Stop a plugin's A/B test"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        await plugin_manager.disable_ab_test(plugin_id)
+        return {"message": f"A/B test on {plugin_id} stopped"}
+    except Exception as e:
+        raise HTTPException(status_code=404, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/ab-test/promote")
+async def promote_ab_version(plugin_id: str, request: Request, force: bool = False):
+    """Replace a plugin with its A/B test variant; pass force to promote even while other plugins depend on either arm"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        await plugin_manager.promote_ab_version(plugin_id, force=force)
+        return {"message": f"A/B variant promoted to {plugin_id}"}
+    except PluginDependentsLoaded as e:
+        raise HTTPException(status_code=409, detail={"error": "DependentsLoaded", "message": str(e), "dependents": e.dependents})
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/export")
+async def export_plugin(plugin_id: str, request: Request):
+    """Export a loaded plugin to a ZIP archive in the exports directory"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    settings = request.app.state.settings
+    try:
+        version = plugin_manager.plugin_manifests.get(plugin_id, {}).get("version", "1.0.0")
+        archive_path = await plugin_manager.export_plugin(
+            plugin_id, settings.export_directory / f"{plugin_id}-{version}.zip"
+        )
+        return {"status": "exported", "plugin_id": plugin_id, "archive_path": str(archive_path)}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/pause")
+async def pause_plugin(plugin_id: str, request: Request):
+    """Pause a plugin without unloading it"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        await plugin_manager.pause_plugin(plugin_id)
+        return {"status": "paused", "plugin_id": plugin_id}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/plugins/{plugin_id}/resume")
+async def resume_plugin(plugin_id: str, request: Request):
+    """Resume a paused plugin and run its queued calls"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        await plugin_manager.resume_plugin(plugin_id)
+        return {"status": "resumed", "plugin_id": plugin_id}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.get("/plugins/audit/call-graph")
+async def get_plugin_call_graph(request: Request):
+    """Get each plugin's declared service usage and any undeclared access"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    return plugin_manager.get_plugin_call_graph()
+
+@router.get("/plugins/metrics/calls")
+async def get_plugin_metrics(request: Request):
+    """Get per-plugin call statistics"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    return {"plugins": plugin_manager.get_plugin_metrics()}
+
+@router.post("/plugins/metrics/reset")
+async def reset_plugin_metrics(request: Request):
+    """Clear per-plugin call statistics"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    plugin_manager.reset_plugin_metrics()
+    return {"message": "Plugin metrics reset"}
+
+@router.post("/plugins/lazy/materialize")
+async def materialize_lazy_plugins(request: Request):
+    """Load every lazy plugin now instead of on its first call"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        return {"materialized": await plugin_manager.materialize_all_lazy_plugins()}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.get("/plugins/startup/load-order")
+async def get_plugin_load_order(request: Request):
+    """Show the resolved autoload order and which plugins load concurrently"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    return asdict(plugin_manager.get_plugin_load_order())
+
+@router.post("/plugins/lock/update")
+async def update_plugin_lock(request: Request):
+    """Regenerate plugin.lock from the currently loaded extension plugins"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    return {"plugins": plugin_manager.update_plugin_lock()}
+
+@router.get("/plugins/lock/check")
+async def check_plugin_lock(request: Request):
+    """Verify loaded extension plugins against plugin.lock"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    return plugin_manager.check_plugin_lock()
+
+@router.get("/plugins/compatibility/{plugin_a}/{plugin_b}")
+async def test_plugin_compatibility(plugin_a: str, plugin_b: str, request: Request):
+    """Check two plugins for known conflicts before deploying them together"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        return asdict(plugin_manager.test_plugin_compatibility(plugin_a, plugin_b))
+    except Exception as e:
+        raise HTTPException(status_code=404, detail=str(e))
+
+@router.get("/plugins/tasks/scheduled")
+async def list_scheduled_tasks(request: Request):
+    """List active plugin background tasks"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    tasks = plugin_manager.list_scheduled_tasks()
+    return {"tasks": tasks, "total": len(tasks)}
+
 @router.get("/plugins/categories")
 async def get_plugin_categories(request: Request):
     """Get available plugin categories"""
@@ -98,4 +461,29 @@ async def get_plugin_categories(request: Request):
         categories = await plugin_manager.get_plugin_categories()
         return {"categories": categories}
     except Exception as e:
-        raise HTTPException(status_code=500, detail=str(e))
\ No newline at end of file
+        raise HTTPException(status_code=500, detail=str(e))
+@router.post("/plugins/groups/{group_name}/load")
+async def load_plugin_group(group_name: str, request: Request):
+    """Load all plugins in a group, rolling back on partial failure"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        plugin_ids = await plugin_manager.load_group(group_name)
+        return {"status": "loaded", "group": group_name, "plugin_ids": plugin_ids}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/plugins/groups/{group_name}/unload")
+async def unload_plugin_group(group_name: str, request: Request):
+    """Unload all plugins in a group"""
+    plugin_manager = getattr(request.app.state, 'plugin_manager', None)
+    if not plugin_manager:
+        raise HTTPException(status_code=503, detail="Plugin manager not initialized")
+    
+    try:
+        plugin_ids = await plugin_manager.unload_group(group_name)
+        return {"status": "unloaded", "group": group_name, "plugin_ids": plugin_ids}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
@@ -0,0 +1,60 @@
+from fastapi import APIRouter, Request, HTTPException
+from pydantic import BaseModel
+from typing import List, Optional
+
+router = APIRouter()
+
+class ProfileSaveRequest(BaseModel):
+    name: str
+
+class ProfileInfo(BaseModel):
+    name: str
+    device_id: Optional[str]
+    plugins: List[str]
+    created_at: Optional[str]
+    active: bool
+
+class ProfileListResponse(BaseModel):
+    profiles: List[ProfileInfo]
+    active_profile: Optional[str]
+    total: int
+
+@router.get("/profiles", response_model=ProfileListResponse)
+async def list_profiles(request: Request):
+    """List saved GPU/plugin profiles"""
+    profile_manager = getattr(request.app.state, 'profile_manager', None)
+    if not profile_manager:
+        raise HTTPException(status_code=503, detail="Profile manager not initialized")
+    
+    profiles = await profile_manager.list_profiles()
+    return ProfileListResponse(
+        profiles=profiles,
+        active_profile=profile_manager.active_profile,
+        total=len(profiles)
+    )
+
+@router.post("/profiles")
+async def save_profile(save_request: ProfileSaveRequest, request: Request):
+    """Save the current GPU device and loaded plugins as a named profile"""
+    profile_manager = getattr(request.app.state, 'profile_manager', None)
+    if not profile_manager:
+        raise HTTPException(status_code=503, detail="Profile manager not initialized")
+    
+    try:
+        profile = await profile_manager.save_profile(save_request.name)
+        return {"status": "saved", "profile": profile}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+@router.post("/profiles/{name}/load")
+async def load_profile(name: str, request: Request):
+    """Restore a saved profile"""
+    profile_manager = getattr(request.app.state, 'profile_manager', None)
+    if not profile_manager:
+        raise HTTPException(status_code=503, detail="Profile manager not initialized")
+    
+    try:
+        profile = await profile_manager.load_profile(name)
+        return {"status": "loaded", "profile": profile}
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
@@ -18,6 +18,7 @@ class TrainingResponse(BaseModel):
     total_epochs: int
     metrics: Dict[str, float]
     estimated_time_remaining: float
+    peak_vram_bytes: Optional[int] = None
 
 class TrainingListResponse(BaseModel):
     trainings: List[TrainingResponse]
@@ -0,0 +1,51 @@
+from fastapi import APIRouter, Request, HTTPException
+from pydantic import BaseModel
+from typing import List
+
+from src.api.endpoints.features import require_admin_api_key
+
+router = APIRouter()
+
+class UserRequest(BaseModel):
+    name: str
+    api_key: str
+    roles: List[str]
+
+def require_admin(request: Request):
+    """Require the admin role, or the admin API key while no users exist yet"""
+    access_control = getattr(request.app.state, 'access_control', None)
+    if not access_control:
+        raise HTTPException(status_code=503, detail="Access control not initialized")
+    
+    if not access_control.enabled:
+        require_admin_api_key(request)
+        return access_control
+    
+    roles = access_control.resolve_roles(request.headers.get("x-api-key", "")) or []
+    if "admin" not in roles:
+        raise HTTPException(status_code=403, detail={"error": "Forbidden", "required_roles": ["admin"]})
+    return access_control
+
+@router.get("/users")
+async def list_users(request: Request):
+    """List configured users and their roles"""
+    access_control = require_admin(request)
+    return {"users": access_control.list_users(), "permissions": access_control.permissions}
+
+@router.post("/users")
+async def add_user(user: UserRequest, request: Request):
+    """Add a user (requires the admin role)"""
+    access_control = require_admin(request)
+    access_control.add_user(user.name, user.api_key, user.roles)
+    return {"message": f"User {user.name} added", "roles": user.roles}
+
+@router.delete("/users/{name}")
+async def revoke_user(name: str, request: Request):
+    """Revoke a user (requires the admin role)"""
+    access_control = require_admin(request)
+    
+    try:
+        access_control.revoke_user(name)
+        return {"message": f"User {name} revoked"}
+    except Exception as e:
+        raise HTTPException(status_code=404, detail=str(e))
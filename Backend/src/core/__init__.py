@@ -1,9 +1,11 @@
 from .gpu_manager import UniversalGPUManager
 from .model_factory import ModelFactory
 from .training_engine import TrainingEngine
+from .profile_manager import ProfileManager
 
 __all__ = [
     "UniversalGPUManager",
     "ModelFactory", 
-    "TrainingEngine"
+    "TrainingEngine",
+    "ProfileManager"
 ]
\ No newline at end of file
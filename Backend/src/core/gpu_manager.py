@@ -6,9 +6,11 @@ import subprocess
 import psutil
 import time
 import os
+import json
 import _wmi
-from typing import Dict, List, Optional, Any, Union
-from dataclasses import dataclass
+from typing import Dict, List, Optional, Any, Union, Tuple
+from pathlib import Path
+from dataclasses import dataclass, field
 from enum import Enum
 
 from src.core.temp_monitor import temp_check
@@ -82,22 +84,72 @@ class GPUDevice:
     supports_fp16: bool
     supports_int8: bool
     max_work_group_size: int
+
+@dataclass
+class DeviceCriteria:
+    """Weights used to rank devices for the device selection UI"""
+    vram_weight: float = 1.0
+    compute_weight: float = 1.0
+    feature_weight: float = 1.0
+    prefer_discrete: bool = True
+    discrete_bonus: float = 0.25
+    required_features: List[str] = field(default_factory=list)  # "fp16", "int8"
+
+# Usable PCIe bandwidth per lane in GB/s, after encoding overhead
+PCIE_LANE_BANDWIDTH_GBPS = {1: 0.25, 2: 0.5, 3: 0.985, 4: 1.969, 5: 3.938, 6: 7.563}
+
+# Bandwidth tests allocate size_bytes twice on the host and once on the device
+MAX_BANDWIDTH_TEST_BYTES = 2 * 1024 * 1024 * 1024
+
+@dataclass
+class BandwidthMeasurement:
+    """Host-to-device and device-to-host transfer rates for the selected device"""
+    device: str
+    size_bytes: int
+    upload_bandwidth_gbps: float
+    download_bandwidth_gbps: float
+    pcie_generation: Optional[int] = None
+    pcie_link_width: Optional[int] = None
+    theoretical_bandwidth_gbps: Optional[float] = None
+    below_expected: bool = False
     
 class UniversalGPUManager:
     """Universal GPU manager supporting all major GPU vendors"""
     
-    def __init__(self):
+    def __init__(self, mock_file: Optional[Path] = None):
         self.devices: List[GPUDevice] = []
         self.selected_device: Optional[GPUDevice] = None
         self.torch_device: Optional[torch.device] = None
         self.initialized = False
         self.opencl_context = None
         self.opencl_queue = None
+        self._init_lock: Optional[asyncio.Lock] = None
+        
+        # Device data read from a JSON file instead of the hardware, for CI machines without GPUs
+        self.mock_file = Path(mock_file) if mock_file else None
+        self.mock_data: Dict[str, Any] = {}
+        
+    async def ensure_initialized(self):
+        """Initialize on first use when startup deferred GPU initialization"""
+        if self.initialized:
+            return
         
+        if self._init_lock is None:
+            self._init_lock = asyncio.Lock()
+        async with self._init_lock:
+            if not self.initialized:
+                await self.initialize()
+    
     async def initialize(self):
         """Initialize GPU manager with device detection"""
         logging.info("🔧 Initializing Universal GPU Manager...")
         
+        if self.mock_file:
+            self._load_mock_devices()
+            self.initialized = True
+            logging.info(f"✅ GPU Manager initialized from mock file {self.mock_file} - Selected: {self.get_device_info()}")
+            return True
+        
         try:
             # Detect all available devices
             await self._detect_all_devices()
@@ -120,6 +172,50 @@ class UniversalGPUManager:
             self.initialized = True
             return True
     
+    def _load_mock_devices(self):
+        """Load devices from the mock file; entries use the get_all_devices schema"""
+        with open(self.mock_file, 'r') as f:
+            self.mock_data = json.load(f)
+        
+        self.devices = []
+        for entry in self.mock_data.get("devices", []):
+            self.devices.append(GPUDevice(
+                device_id=entry["id"],
+                name=entry["name"],
+                vendor=VendorType(entry.get("vendor", VendorType.UNKNOWN.value)),
+                device_type=DeviceType(entry.get("type", DeviceType.CPU.value)),
+                compute_units=entry.get("compute_units", 1),
+                total_memory_mb=entry.get("memory_mb", 0),
+                available_memory_mb=entry.get("available_memory_mb", entry.get("memory_mb", 0)),
+                memory_usage_percent=entry.get("memory_usage_percent", 0.0),
+                temperature_c=entry.get("temperature_c"),
+                power_usage_w=entry.get("power_usage_w"),
+                driver_version=entry.get("driver_version", "mock"),
+                compute_capability=entry.get("compute_capability"),
+                performance_score=entry.get("performance_score", 100),
+                is_discrete=entry.get("is_discrete", False),
+                supports_fp16=entry.get("supports_fp16", True),
+                supports_int8=entry.get("supports_int8", True),
+                max_work_group_size=entry.get("max_work_group_size", 1)
+            ))
+        
+        if not self.devices:
+            raise ValueError(f"GPU mock file {self.mock_file} defines no devices")
+        
+        selected = [d for d in self.devices if self.mock_data.get("selected_device_id") == d.device_id]
+        self.selected_device = selected[0] if selected else max(self.devices, key=lambda d: d.performance_score)
+        
+        # Tensors still run on the CPU; only the reported device data is mocked
+        self.torch_device = torch.device("cpu")
+    
+    def _mock_output(self, handle: str, **request: Any) -> Optional[Dict[str, Any]]:
+        """The mock file's response for a compute call, or None to run it for real"""
+        # mock_outputs is keyed by method name; the call's own arguments fill fields the entry leaves out
+        output = self.mock_data.get("mock_outputs", {}).get(handle)
+        if output is None:
+            return None
+        return {**{k: v for k, v in request.items() if v is not None}, **output}
+    
     async def _detect_all_devices(self):
         """Detect all available GPU devices across all platforms"""
         self.devices = []
@@ -391,6 +487,10 @@ class UniversalGPUManager:
         if not self.selected_device:
             return
         
+        if self.mock_file:
+            self.torch_device = torch.device("cpu")
+            return
+        
         try:
             if self.selected_device.device_type == DeviceType.CUDA:
                 device_id = int(self.selected_device.device_id.split(':')[1])
@@ -657,8 +757,42 @@ class UniversalGPUManager:
             devices_info.append(info)
         return devices_info
     
+    def get_adapter_score(self, criteria: DeviceCriteria) -> List[Tuple[int, float]]:
+        """Score every detected device against the criteria, sorted by descending score"""
+        if not self.devices:
+            return []
+        
+        max_memory = max(d.total_memory_mb for d in self.devices) or 1
+        max_compute = max(d.compute_units for d in self.devices) or 1
+        feature_support = {
+            "fp16": lambda d: d.supports_fp16,
+            "int8": lambda d: d.supports_int8
+        }
+        
+        for feature in criteria.required_features:
+            if feature not in feature_support:
+                raise ValueError(f"Unknown feature: {feature}. Must be one of: {list(feature_support)}")
+        
+        scores = []
+        for index, device in enumerate(self.devices):
+            score = criteria.vram_weight * (device.total_memory_mb / max_memory)
+            score += criteria.compute_weight * (device.compute_units / max_compute)
+            
+            if criteria.required_features:
+                supported = sum(1 for f in criteria.required_features if feature_support[f](device))
+                score += criteria.feature_weight * (supported / len(criteria.required_features))
+            
+            if criteria.prefer_discrete and device.is_discrete:
+                score += criteria.discrete_bonus
+            
+            scores.append((index, round(score, 4)))
+        
+        return sorted(scores, key=lambda s: s[1], reverse=True)
+    
     async def select_device(self, device_id: str):
         """Manually select a device"""
+        await self.ensure_initialized()
+        
         target_device = None
         for device in self.devices:
             if device.device_id == device_id:
@@ -674,6 +808,8 @@ class UniversalGPUManager:
     
     async def set_gpu_preference(self, preference: str):
         """Set GPU preference: 'auto', 'gpu_only', 'cpu_only', 'nvidia_only', 'amd_only', 'intel_only'"""
+        await self.ensure_initialized()
+        
         valid_preferences = ['auto', 'gpu_only', 'cpu_only', 'nvidia_only', 'amd_only', 'intel_only']
         
         if preference not in valid_preferences:
@@ -814,9 +950,15 @@ class UniversalGPUManager:
     
     async def get_device_status(self) -> Dict[str, Any]:
         """Get current device status and utilization"""
+        await self.ensure_initialized()
+        
         if not self.selected_device:
             return {"error": "No device selected"}
         
+        mock_output = self._mock_output("get_device_status")
+        if mock_output is not None:
+            return mock_output
+        
         device = self.selected_device
         status = {
             "device_id": device.device_id,
@@ -861,17 +1003,23 @@ class UniversalGPUManager:
         except Exception as e:
             logging.warning(f"Cache clearing failed: {e}")
     
-    async def run_benchmark(self) -> Dict[str, Any]:
-        """Run cross-platform benchmark"""
+    async def run_benchmark(self, matrix_sizes: Optional[List[int]] = None) -> Dict[str, Any]:
+        """Run cross-platform benchmark; pass smaller matrix_sizes for a quick run"""
+        await self.ensure_initialized()
+        
         if not self.selected_device:
             return {"error": "No device selected"}
         
+        mock_output = self._mock_output("run_benchmark", matrix_sizes=matrix_sizes)
+        if mock_output is not None:
+            return mock_output
+        
         logging.info("🏃 Running cross-platform GPU benchmark...")
         device = self.get_device()
         
         try:
             # Benchmark parameters
-            matrix_sizes = [1024, 2048, 4096]
+            matrix_sizes = matrix_sizes or [1024, 2048, 4096]
             iterations = 5
             results = {}
             
@@ -943,8 +1091,95 @@ class UniversalGPUManager:
                 "device": self.get_device_info()
             }
     
+    def _synchronize_device(self, device: torch.device):
+        """Wait for queued work on the device to finish before reading the clock"""
+        if device.type == "cuda":
+            torch.cuda.synchronize()
+        elif device.type == "mps" and hasattr(torch.mps, 'synchronize'):
+            torch.mps.synchronize()
+    
+    def _get_pcie_link(self, device: torch.device) -> Tuple[Optional[int], Optional[int]]:
+        """Get the current PCIe generation and lane width of an NVIDIA device"""
+        if device.type != "cuda" or not PYNVML_AVAILABLE:
+            return None, None
+        
+        try:
+            handle = pynvml.nvmlDeviceGetHandleByIndex(device.index or 0)
+            return (pynvml.nvmlDeviceGetCurrPcieLinkGeneration(handle),
+                    pynvml.nvmlDeviceGetCurrPcieLinkWidth(handle))
+        except Exception as e:
+            logging.warning(f"PCIe link query failed: {e}")
+            return None, None
+    
+    async def measure_memory_bandwidth(self, size_bytes: int = 256 * 1024 * 1024) -> BandwidthMeasurement:
+        """Time a host-to-device upload and device-to-host download of size_bytes"""
+        await self.ensure_initialized()
+        
+        if not self.selected_device:
+            raise ValueError("No device selected")
+        if not 0 < size_bytes <= MAX_BANDWIDTH_TEST_BYTES:
+            raise ValueError(f"size_bytes must be between 1 and {MAX_BANDWIDTH_TEST_BYTES}")
+        
+        mock_output = self._mock_output("measure_memory_bandwidth", device=self.get_device_info(), size_bytes=size_bytes)
+        if mock_output is not None:
+            return BandwidthMeasurement(**mock_output)
+        
+        device = self.get_device()
+        if device.type == "cpu":
+            raise ValueError("Bandwidth measurement needs a GPU device")
+        
+        # Pinned host memory in both directions so the copies are not limited by pageable staging buffers
+        host_buffer = torch.empty(size_bytes, dtype=torch.uint8)
+        download_buffer = torch.empty(size_bytes, dtype=torch.uint8)
+        if device.type == "cuda":
+            host_buffer = host_buffer.pin_memory()
+            download_buffer = download_buffer.pin_memory()
+        
+        def timed_transfer() -> Tuple[float, float]:
+            start_time = time.perf_counter()
+            device_buffer = host_buffer.to(device)
+            self._synchronize_device(device)
+            upload_seconds = time.perf_counter() - start_time
+            
+            start_time = time.perf_counter()
+            download_buffer.copy_(device_buffer)
+            self._synchronize_device(device)
+            download_seconds = time.perf_counter() - start_time
+            return upload_seconds, download_seconds
+        
+        # Transfers block until the device is done, so they run off the event loop
+        await asyncio.to_thread(timed_transfer)  # warm up; the first transfer includes allocation and driver setup
+        upload_seconds, download_seconds = await asyncio.to_thread(timed_transfer)
+        
+        measurement = BandwidthMeasurement(
+            device=self.get_device_info(),
+            size_bytes=size_bytes,
+            upload_bandwidth_gbps=size_bytes / upload_seconds / 1e9,
+            download_bandwidth_gbps=size_bytes / download_seconds / 1e9
+        )
+        
+        generation, width = self._get_pcie_link(device)
+        if generation in PCIE_LANE_BANDWIDTH_GBPS and width:
+            measurement.pcie_generation = generation
+            measurement.pcie_link_width = width
+            measurement.theoretical_bandwidth_gbps = PCIE_LANE_BANDWIDTH_GBPS[generation] * width
+            
+            slowest = min(measurement.upload_bandwidth_gbps, measurement.download_bandwidth_gbps)
+            if slowest < 0.6 * measurement.theoretical_bandwidth_gbps:
+                measurement.below_expected = True
+                logging.warning(
+                    f"⚠️ Transfer bandwidth {slowest:.2f} GB/s is below 60% of the "
+                    f"{measurement.theoretical_bandwidth_gbps:.2f} GB/s expected for PCIe Gen{generation} x{width}"
+                )
+        
+        logging.info(f"✅ Bandwidth - upload {measurement.upload_bandwidth_gbps:.2f} GB/s, "
+                     f"download {measurement.download_bandwidth_gbps:.2f} GB/s")
+        return measurement
+    
     async def optimize_for_model_size(self, estimated_model_size_mb: int) -> Dict[str, Any]:
         """Optimize device selection and settings for a specific model size"""
+        await self.ensure_initialized()
+        
         recommendations = {
             "recommended_device": None,
             "memory_sufficient": False,
@@ -27,6 +27,7 @@ class ModelFactory:
                           architecture: Dict[str, Any], 
                           hyperparameters: Dict[str, Any]) -> str:
         """Create a new neural network model"""
+        await self.gpu_manager.ensure_initialized()
         model_id = str(uuid.uuid4())
         
         # Create model based on type
@@ -0,0 +1,99 @@
+import json
+import logging
+from datetime import datetime
+from pathlib import Path
+from typing import Dict, List, Any, Optional
+
+
+class ProfileManager:
+    """Saves and restores named GPU/plugin configurations"""
+
+    def __init__(self, gpu_manager, plugin_manager, profile_directory: Path = Path("profiles")):
+        self.gpu_manager = gpu_manager
+        self.plugin_manager = plugin_manager
+        self.profile_directory = Path(profile_directory)
+        self.profile_directory.mkdir(exist_ok=True)
+        self.active_profile: Optional[str] = None
+
+    def _profile_path(self, name: str) -> Path:
+        """Get the JSON file path for a profile"""
+        if not name or "/" in name or "\\" in name or name.startswith("."):
+            raise ValueError(f"Invalid profile name: {name}")
+        return self.profile_directory / f"{name}.json"
+
+    async def save_profile(self, name: str) -> Dict[str, Any]:
+        """Save the current GPU device and loaded plugins as a named profile"""
+        await self.gpu_manager.ensure_initialized()
+        selected_device = self.gpu_manager.selected_device
+        profile = {
+            "name": name,
+            "device_id": selected_device.device_id if selected_device else None,
+            "plugins": self.plugin_manager.get_loaded_plugins(),
+            "enabled_plugins": sorted(self.plugin_manager.enabled_plugins),
+            "created_at": datetime.now().isoformat()
+        }
+
+        with open(self._profile_path(name), 'w') as f:
+            json.dump(profile, f, indent=2)
+
+        self.active_profile = name
+        logging.info(f"💾 Saved profile: {name}")
+        return profile
+
+    async def load_profile(self, name: str) -> Dict[str, Any]:
+        """Restore a profile: select its GPU device and load exactly its plugins"""
+        profile_path = self._profile_path(name)
+        if not profile_path.exists():
+            raise ValueError(f"Profile not found: {name}")
+
+        with open(profile_path, 'r') as f:
+            profile = json.load(f)
+
+        if profile.get("device_id"):
+            await self.gpu_manager.select_device(profile["device_id"])
+
+        wanted_plugins = profile.get("plugins", [])
+        loaded_before = set(self.plugin_manager.get_loaded_plugins())
+        enabled_before = set(self.plugin_manager.enabled_plugins)
+        for plugin_id in loaded_before:
+            if plugin_id not in wanted_plugins:
+                await self.plugin_manager.unload_plugin(plugin_id, force=True)
+
+        # Profiles saved before enabled_plugins was recorded keep each plugin's current state; new loads are enabled
+        enabled_plugins = profile.get("enabled_plugins")
+        for plugin_id in wanted_plugins:
+            await self.plugin_manager.load_plugin(plugin_id)
+            if enabled_plugins is not None:
+                enable = plugin_id in enabled_plugins
+            else:
+                enable = plugin_id in enabled_before or plugin_id not in loaded_before
+            if enable:
+                await self.plugin_manager.enable_plugin(plugin_id)
+            else:
+                await self.plugin_manager.disable_plugin(plugin_id)
+
+        self.active_profile = name
+        logging.info(f"✅ Loaded profile: {name}")
+        return profile
+
+    async def list_profiles(self) -> List[Dict[str, Any]]:
+        """List saved profiles with their metadata"""
+        profiles = []
+
+        for profile_path in sorted(self.profile_directory.glob("*.json")):
+            try:
+                with open(profile_path, 'r') as f:
+                    profile = json.load(f)
+            except Exception as e:
+                logging.warning(f"⚠️ Skipping unreadable profile {profile_path}: {e}")
+                continue
+
+            profiles.append({
+                "name": profile.get("name", profile_path.stem),
+                "device_id": profile.get("device_id"),
+                "plugins": profile.get("plugins", []),
+                "created_at": profile.get("created_at"),
+                "active": profile_path.stem == self.active_profile
+            })
+
+        return profiles
@@ -51,6 +51,7 @@ class TrainingEngine:
             },
             "start_time": time.time(),
             "estimated_time_remaining": 0.0,
+            "peak_vram_bytes": None,  # CUDA only; None on other devices
             "config": training_config,
             "dataset_config": dataset_config,
             "validation_config": validation_config
@@ -68,6 +69,7 @@ class TrainingEngine:
     async def _training_loop(self, training_id: str, model: nn.Module,
                            training_config: Dict[str, Any], dataset_config: Dict[str, Any]):
         """Main training loop"""
+        device = None
         try:
             training_info = self.active_trainings[training_id]
             training_info["status"] = "running"
@@ -78,7 +80,11 @@ class TrainingEngine:
             train_loader = await self._create_dataloader(dataset_config, training_config)
             
             epochs = training_config.get("epochs", 100)
+            await self.gpu_manager.ensure_initialized()
             device = self.gpu_manager.get_device()
+            # Peak stats are per device, so jobs sharing a GPU see each other's allocations
+            if device.type == "cuda":
+                torch.cuda.reset_peak_memory_stats(device)
             
             # Training loop
             for epoch in range(epochs):
@@ -117,6 +123,7 @@ class TrainingEngine:
                 training_info["current_epoch"] = epoch + 1
                 training_info["metrics"]["loss"] = avg_loss
                 training_info["metrics"]["accuracy"] = accuracy
+                self._record_peak_vram(training_info, device)
                 
                 # Estimate remaining time
                 elapsed_time = time.time() - training_info["start_time"]
@@ -149,9 +156,16 @@ class TrainingEngine:
             logging.error(f"❌ Training {training_id} failed: {e}")
         finally:
             # Cleanup
+            if device is not None:
+                self._record_peak_vram(training_info, device)
             if training_id in self.training_tasks:
                 del self.training_tasks[training_id]
     
+    def _record_peak_vram(self, training_info: Dict[str, Any], device: torch.device):
+        """Store the highest VRAM allocation since the job started"""
+        if device.type == "cuda":
+            training_info["peak_vram_bytes"] = torch.cuda.max_memory_allocated(device)
+    
     def _create_optimizer(self, model: nn.Module, config: Dict[str, Any]) -> optim.Optimizer:
         """Create optimizer based on configuration"""
         optimizer_type = config.get("optimizer", "adam").lower()
@@ -288,6 +302,7 @@ class TrainingEngine:
             "progress": training_info["current_epoch"] / training_info["total_epochs"],
             "elapsed_time": time.time() - training_info["start_time"],
             "estimated_remaining": training_info["estimated_time_remaining"],
+            "peak_vram_bytes": training_info["peak_vram_bytes"],
             "status": training_info["status"]
         }
     
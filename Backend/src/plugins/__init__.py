@@ -1,6 +1,9 @@
 from .plugin_manager import PluginManager, PluginBase
+from .service_locator import ServiceLocator, ScopedServiceLocator
 
 __all__ = [
     "PluginManager",
-    "PluginBase"
+    "PluginBase",
+    "ServiceLocator",
+    "ScopedServiceLocator"
 ]
\ No newline at end of file
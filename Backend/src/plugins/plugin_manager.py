@@ -1,13 +1,91 @@
 import importlib
 import importlib.util
+import ast
 import logging
+import logging.handlers
+import contextlib
+import contextvars
+import copy
 import json
+import re
+import hashlib
+import shutil
+import sys
+import zipfile
 import asyncio
+import time
+from collections import deque
+import psutil
 import torch
 import torch.nn as nn
-from typing import Dict, List, Any, Optional, Type
+from typing import Dict, List, Any, Optional, Tuple, Type
 from pathlib import Path
 from abc import ABC, abstractmethod
+from dataclasses import asdict, dataclass, field
+
+from src.plugins.service_locator import ServiceLocator, ScopedServiceLocator
+from src.utils.metrics_registry import Histogram
+
+# Plugin API version implemented by this backend; manifests may require a minimum
+PLUGIN_API_VERSION = 1
+
+# startup_priority for plugins that set none, or a non-numeric one
+DEFAULT_STARTUP_PRIORITY = 0
+
+# Services that plugins must declare in the call_graph section of their manifest
+SERVICE_CALL_FLAGS = {
+    "gpu_manager": "calls_gpu",
+    "broadcast_channel": "calls_network",
+}
+
+# Call graph flags a plugin can gain at runtime through upgrade_plugin_capabilities
+PLUGIN_CAPABILITIES = ("calls_gpu", "calls_network", "calls_filesystem")
+
+# Plugin calls in progress in the current task, outermost first
+_plugin_call_stack: contextvars.ContextVar = contextvars.ContextVar("plugin_call_stack", default=())
+
+# (stdout, stderr) log streams of the plugin whose code the current task is running, if any
+_plugin_output_streams: contextvars.ContextVar = contextvars.ContextVar("plugin_output_streams", default=None)
+
+class PluginCallDepthExceeded(RuntimeError):
+    """Raised when nested plugin calls exceed the configured maximum depth"""
+
+class CapabilityRemovalRequiresReload(ValueError):
+    """Raised when a capability upgrade would drop a capability clients may already rely on"""
+
+class PluginDependentsLoaded(ValueError):
+    """Raised when unloading a plugin would break loaded plugins that depend on it"""
+    
+    def __init__(self, plugin_id: str, dependents: List[str]):
+        super().__init__(f"Plugin {plugin_id} is required by loaded plugins: {', '.join(dependents)}")
+        self.dependents = dependents
+
+class PluginInABTest(ValueError):
+    """Raised when unloading a plugin would remove an arm of a running A/B test"""
+    
+    def __init__(self, plugin_id: str, test_plugin_id: str):
+        super().__init__(f"Plugin {plugin_id} is an arm of the A/B test on {test_plugin_id}; stop or promote the test first")
+        self.test_plugin_id = test_plugin_id
+
+# Manifest parameter types -> JSON Schema types
+PARAMETER_SCHEMA_TYPES = {
+    "int": "integer",
+    "float": "number",
+    "bool": "boolean",
+    "list": "array",
+    "enum": "string",
+    "str": "string",
+}
+
+# Extension plugin IDs are namespaced by vendor, e.g. "acme.processor"
+PLUGIN_ID_PATTERN = re.compile(r"^[a-z0-9_-]+(\.[a-z0-9_-]+)+$")
+
+# Un-namespaced IDs from before namespacing still load, with a deprecation warning
+LEGACY_PLUGIN_ID_PATTERN = re.compile(r"^[A-Za-z0-9_-]+$")
+
+def is_legacy_plugin_id(plugin_id: str) -> bool:
+    """Check whether an ID is a deprecated un-namespaced extension plugin ID"""
+    return not PLUGIN_ID_PATTERN.match(plugin_id) and bool(LEGACY_PLUGIN_ID_PATTERN.match(plugin_id))
 
 class PluginBase(ABC):
     """Base class for all plugins"""
@@ -36,17 +114,202 @@ class PluginBase(ABC):
     async def create_component(self, component_type: str, config: Dict[str, Any]) -> Any:
         """Create a neural component of specified type"""
         pass
+    
+    async def run_self_test(self) -> Optional[Tuple[bool, str]]:
+        """Optionally verify the plugin works; return (passed, message) or None if not supported"""
+        return None
+    
+    async def health_check(self) -> Optional[bool]:
+        """Optionally report whether the plugin is healthy; None if not supported"""
+        return None
+    
+    def describe_api(self) -> Optional[List[Dict[str, Any]]]:
+        """Optionally describe each component: name, description, parameter_schema, response_schema"""
+        return None
+
+@dataclass
+class DryRunReport:
+    """Result of validating a plugin without loading it"""
+    plugin_id: str
+    manifest_valid: bool = False
+    manifest_errors: List[str] = field(default_factory=list)
+    signature_valid: bool = False
+    dependencies_satisfied: bool = False
+    missing_dependencies: List[str] = field(default_factory=list)
+    required_services_available: bool = False
+    missing_services: List[str] = field(default_factory=list)
+    estimated_memory_mb: int = 0
+
+@dataclass
+class PluginCallStats:
+    """Call statistics for one plugin, kept after the plugin is unloaded"""
+    total_calls: int = 0
+    total_duration_us: int = 0
+    error_count: int = 0
+    last_call_unix_ms: int = 0
+    self_test_passed: Optional[bool] = None
+
+@dataclass
+class CompatibilityReport:
+    """Known conflicts between two plugins"""
+    plugin_a: str
+    plugin_b: str
+    compatible: bool = True
+    issues: List[str] = field(default_factory=list)
+
+@dataclass
+class TestReport:
+    """Result of running a plugin's self-test"""
+    plugin_id: str
+    passed: bool
+    message: str
+    duration_ms: int
+    skipped: bool = False
+
+@dataclass
+class LoadOrder:
+    """Resolved startup order of autoload plugins; each group loads concurrently"""
+    groups: List[List[str]] = field(default_factory=list)
+    priorities: Dict[str, int] = field(default_factory=dict)
+    skipped: Dict[str, str] = field(default_factory=dict)
+
+class _PluginLogStream:
+    """File-like object that forwards writes to a plugin logger line by line"""
+    
+    def __init__(self, logger: logging.Logger, level: int):
+        self.logger = logger
+        self.level = level
+        self.buffer = ""
+    
+    def write(self, text: str) -> int:
+        self.buffer += text
+        while "\n" in self.buffer:
+            line, self.buffer = self.buffer.split("\n", 1)
+            if line:
+                self.logger.log(self.level, line)
+        return len(text)
+    
+    def flush(self):
+        if self.buffer:
+            self.logger.log(self.level, self.buffer)
+            self.buffer = ""
+
+class _PluginOutputRouter:
+    """Stands in for sys.stdout or sys.stderr, sending writes made by plugin code to that plugin's log.
+    
+    The redirection target is per task (_plugin_output_streams), so concurrent requests and
+    background tasks never capture each other's output; other writes reach the original stream.
+    """
+    
+    def __init__(self, original, stream_index: int):
+        self.original = original
+        self.stream_index = stream_index
+    
+    def _target(self):
+        streams = _plugin_output_streams.get()
+        return streams[self.stream_index] if streams else self.original
+    
+    def write(self, text: str) -> int:
+        return self._target().write(text)
+    
+    def flush(self):
+        self._target().flush()
+    
+    def __getattr__(self, name: str):
+        return getattr(self.original, name)
+
+def _install_plugin_output_router():
+    """Replace sys.stdout and sys.stderr with routers once; later calls are no-ops"""
+    if not isinstance(sys.stdout, _PluginOutputRouter):
+        sys.stdout = _PluginOutputRouter(sys.stdout, 0)
+    if not isinstance(sys.stderr, _PluginOutputRouter):
+        sys.stderr = _PluginOutputRouter(sys.stderr, 1)
 
 class PluginManager:
     """Manages plugin loading, unloading, and lifecycle"""
     
-    def __init__(self, plugin_directory: Path):
+    def __init__(self, plugin_directory: Path, log_directory: Path = Path("logs"),
+                 log_max_bytes: int = 10 * 1024 * 1024):
         self.plugin_directory = Path(plugin_directory)
         self.plugin_directory.mkdir(exist_ok=True)
         
+        # Per-plugin log files, kept separate from the backend log
+        self.log_directory = Path(log_directory)
+        self.log_directory.mkdir(exist_ok=True)
+        self.log_max_bytes = log_max_bytes
+        
         self.loaded_plugins: Dict[str, PluginBase] = {}
         self.plugin_manifests: Dict[str, Dict[str, Any]] = {}
         self.enabled_plugins: set = set()
+        self.plugin_paths: Dict[str, Path] = {}
+        
+        # SHA-256 of each loaded plugin's main module, used to detect on-disk changes
+        self.plugin_hashes: Dict[str, str] = {}
+        
+        # Pinned extension plugin versions and hashes; verified on load when the file exists
+        self.lock_file_path = self.plugin_directory / "plugin.lock"
+        
+        # Extension plugins with "autoload": true, as resolved by the last load_autoload_plugins
+        self.autoload_plugins: List[str] = []
+        
+        # Autoload skips plugins whose "load_in_environments" does not include this
+        self.environment = "development"
+        
+        # Autoload plugins with "lazy": true wait here until their first call: plugin_id -> plugin path
+        self.lazy_plugins: Dict[str, Path] = {}
+        self._lazy_load_locks: Dict[str, asyncio.Lock] = {}
+        
+        # Per-plugin call statistics
+        self.plugin_metrics: Dict[str, PluginCallStats] = {}
+        self.plugin_call_durations: Dict[Tuple[str, str], Histogram] = {}
+        
+        # Nested plugin calls beyond this depth are rejected to stop runaway recursion
+        self.max_call_depth = 16
+        
+        # A/B tests: plugin_id -> variant plugin, traffic split and per-arm statistics
+        self.ab_tests: Dict[str, Dict[str, Any]] = {}
+        
+        # Cloned plugin instances: clone_id -> source plugin_id
+        self.plugin_clones: Dict[str, str] = {}
+        
+        # Previous main module versions kept in <plugin>/.history/ for rollback
+        self.version_history_count = 3
+        
+        # After reload_plugin, a failing health check within this window rolls the plugin back
+        self.rollback_window_ms = 30000
+        self.rollback_watchers: Dict[str, asyncio.Task] = {}
+        
+        # Extension plugins running an archived main module after an automatic rollback: plugin_id -> history file.
+        # The source file keeps the user's edits until the next reload
+        self.plugin_module_overrides: Dict[str, Path] = {}
+        
+        # Automatic rollbacks that could not restore the previous version: plugin_id -> error, shown in plugin info
+        self.rollback_failures: Dict[str, str] = {}
+        
+        # Release tags (tag -> version hash) per plugin
+        self.registry_path = self.plugin_directory / "plugin_registry.json"
+        
+        # Services handed to plugins at initialization time
+        self.services = ServiceLocator()
+        
+        # Declared plugin call graphs, and service requests that went outside them
+        self.plugin_call_graphs: Dict[str, Dict[str, Any]] = {}
+        self.call_graph_violations: deque = deque(maxlen=1000)
+        
+        # Paused plugins queue incoming calls until resumed
+        self.paused_plugins: set = set()
+        self.pending_calls: Dict[str, deque] = {}
+        self.max_pending_calls = 100
+        
+        # Background tasks for plugins that declare a schedule in their manifest
+        self.scheduled_tasks: Dict[str, Dict[str, Any]] = {}
+        
+        # Core plugins that ship with Archetype: plugin_id -> (module, class)
+        self.core_plugin_modules: Dict[str, tuple] = {
+            "mlp_core": ("src.plugins.core.mlp_plugin", "MLPPlugin"),
+            "rnn_core": ("src.plugins.core.rnn_plugin", "RNNPlugin"),
+            "cnn_core": ("src.plugins.core.cnn_plugin", "CNNPlugin"),
+        }
         
         # Core plugin directory
         self.core_plugin_directory = self.plugin_directory / "core"
@@ -60,34 +323,27 @@ class PluginManager:
         """Load core plugins that ship with Archetype"""
         logging.info("🔌 Loading core plugins...")
         
-        # Load MLP plugin
-        await self._load_plugin_from_module("mlp_core", "src.plugins.core.mlp_plugin", "MLPPlugin")
-        
-        # Load RNN plugin  
-        await self._load_plugin_from_module("rnn_core", "src.plugins.core.rnn_plugin", "RNNPlugin")
-        
-        # Load CNN plugin
-        await self._load_plugin_from_module("cnn_core", "src.plugins.core.cnn_plugin", "CNNPlugin")
+        for plugin_id, (module_path, class_name) in self.core_plugin_modules.items():
+            await self._load_plugin_from_module(plugin_id, module_path, class_name)
         
         logging.info(f"✅ Loaded {len(self.loaded_plugins)} core plugins")
     
     async def _load_plugin_from_module(self, plugin_id: str, module_path: str, class_name: str):
         """Load plugin from Python module"""
         try:
-            # Import module
-            module = importlib.import_module(module_path)
-            
-            # Get plugin class
-            plugin_class = getattr(module, class_name)
-            
-            # Create plugin instance
-            plugin_instance = plugin_class()
+            # Import module and create plugin instance; module-level code is plugin output too
+            with self._plugin_output(plugin_id):
+                module = importlib.import_module(module_path)
+                plugin_class = getattr(module, class_name)
+                plugin_instance = plugin_class()
             
             # Initialize plugin
-            if await plugin_instance.initialize():
+            if await self._initialize_plugin(plugin_id, plugin_instance):
                 self.loaded_plugins[plugin_id] = plugin_instance
                 self.plugin_manifests[plugin_id] = plugin_instance.get_manifest()
                 self.enabled_plugins.add(plugin_id)
+                self._record_plugin_hash(plugin_id)
+                self._start_scheduled_task(plugin_id)
                 
                 logging.info(f"✅ Loaded plugin: {plugin_id}")
             else:
@@ -96,8 +352,8 @@ class PluginManager:
         except Exception as e:
             logging.error(f"❌ Failed to load plugin {plugin_id}: {e}")
     
-    async def load_plugin_from_file(self, plugin_path: Path) -> str:
-        """Load plugin from file"""
+    async def load_plugin_from_file(self, plugin_path: Path, module_path: Optional[Path] = None) -> str:
+        """Load plugin from file; module_path loads an archived main module instead of the plugin's own"""
         if not plugin_path.exists():
             raise ValueError(f"Plugin file not found: {plugin_path}")
         
@@ -110,14 +366,20 @@ class PluginManager:
             manifest = json.load(f)
         
         plugin_id = manifest["id"]
+        self._check_extension_plugin(plugin_id, plugin_path, manifest)
         
         # Load Python module
         main_module = manifest.get("main_module", "plugin.py")
-        module_path = plugin_path / main_module
+        module_override = module_path
+        if module_override is None:
+            self.plugin_module_overrides.pop(plugin_id, None)
+            module_path = plugin_path / main_module
         
         if not module_path.exists():
             raise ValueError(f"Plugin main module not found: {module_path}")
         
+        self._verify_plugin_lock(plugin_id, manifest, module_path)
+        
         # Import module dynamically
         spec = importlib.util.spec_from_file_location(plugin_id, module_path)
 
@@ -125,22 +387,30 @@ class PluginManager:
             module = importlib.util.module_from_spec(spec)
 
             if spec.loader is not None:
-                spec.loader.exec_module(module)
+                with self._plugin_output(plugin_id):
+                    spec.loader.exec_module(module)
         
         # Get plugin class
         plugin_class_name = manifest.get("plugin_class", "Plugin")
         plugin_class = getattr(module, plugin_class_name)
         
         # Create and initialize plugin
-        plugin_instance = plugin_class()
-        if await plugin_instance.initialize():
+        with self._plugin_output(plugin_id):
+            plugin_instance = plugin_class()
+        if await self._initialize_plugin(plugin_id, plugin_instance, manifest):
             self.loaded_plugins[plugin_id] = plugin_instance
             self.plugin_manifests[plugin_id] = manifest
+            self.plugin_paths[plugin_id] = plugin_path
+            if module_override is not None:
+                self.plugin_module_overrides[plugin_id] = module_override
+            self._record_plugin_hash(plugin_id)
+            self._archive_plugin_version(plugin_id)
+            self._start_scheduled_task(plugin_id)
             
             logging.info(f"✅ Loaded external plugin: {plugin_id}")
             return plugin_id
         else:
-            raise ValueError(f"Unknown component type") # TODO: {component_type}) work on fix later
+            raise ValueError(f"Failed to initialize plugin: {plugin_id}")
     
     def _create_cnn_layer(self, config: Dict[str, Any]) -> nn.Module:
         """Create CNN layer"""
@@ -257,7 +527,14 @@ class PluginManager:
                 "plugin_type": manifest.get("type", "neural_component"),
                 "loaded": plugin_id in self.loaded_plugins,
                 "enabled": plugin_id in self.enabled_plugins,
+                "paused": plugin_id in self.paused_plugins,
+                "lazy_pending": plugin_id in self.lazy_plugins,
                 "dependencies": manifest.get("dependencies", []),
+                "sha256_hash": self.plugin_hashes.get(plugin_id, ""),
+                "available_rollback_versions": self.get_rollback_versions(plugin_id),
+                "is_clone": plugin_id in self.plugin_clones,
+                "cloned_from": self.plugin_clones.get(plugin_id),
+                "rollback_error": self.rollback_failures.get(plugin_id),
                 "manifest": manifest
             }
             plugins.append(plugin_info)
@@ -280,6 +557,11 @@ class PluginManager:
             "loaded": plugin_id in self.loaded_plugins,
             "enabled": plugin_id in self.enabled_plugins,
             "dependencies": manifest.get("dependencies", []),
+            "sha256_hash": self.plugin_hashes.get(plugin_id, ""),
+            "available_rollback_versions": self.get_rollback_versions(plugin_id),
+            "is_clone": plugin_id in self.plugin_clones,
+            "cloned_from": self.plugin_clones.get(plugin_id),
+            "rollback_error": self.rollback_failures.get(plugin_id),
             "neural_components": self.loaded_plugins[plugin_id].get_neural_component_types() if plugin_id in self.loaded_plugins else [],
             "manifest": manifest
         }
@@ -294,14 +576,292 @@ class PluginManager:
     
     async def create_neural_component(self, plugin_id: str, component_type: str, config: Dict[str, Any]) -> Any:
         """Create neural component using plugin"""
+        if plugin_id in self.lazy_plugins:
+            await self.materialize_lazy_plugin(plugin_id)
+        
+        call_stack = _plugin_call_stack.get()
+        if len(call_stack) >= self.max_call_depth:
+            trace = " -> ".join(f"{pid}.{ctype}" for pid, ctype in call_stack)
+            logging.error(f"❌ Plugin call depth {len(call_stack)} exceeded calling {plugin_id}.{component_type}: {trace}")
+            raise PluginCallDepthExceeded(f"Plugin call depth exceeded ({len(call_stack)} >= {self.max_call_depth})")
+        
+        token = _plugin_call_stack.set(call_stack + ((plugin_id, component_type),))
+        try:
+            if plugin_id in self.ab_tests:
+                return await self._create_ab_test_component(plugin_id, component_type, config)
+            return await self._create_plugin_component(plugin_id, component_type, config)
+        finally:
+            _plugin_call_stack.reset(token)
+    
+    async def _create_plugin_component(self, plugin_id: str, component_type: str, config: Dict[str, Any]) -> Any:
+        """Create a component on exactly this plugin, without A/B routing"""
         if plugin_id not in self.loaded_plugins:
             raise ValueError(f"Plugin {plugin_id} not loaded")
         
         if plugin_id not in self.enabled_plugins:
             raise ValueError(f"Plugin {plugin_id} not enabled")
         
+        if component_type not in self.loaded_plugins[plugin_id].get_neural_component_types():
+            raise ValueError(f"Plugin {plugin_id} does not support component type: {component_type}")
+        
+        if plugin_id in self.paused_plugins:
+            queue = self.pending_calls.setdefault(plugin_id, deque())
+            if len(queue) >= self.max_pending_calls:
+                raise RuntimeError(f"Plugin {plugin_id} is paused and its call queue is full")
+            
+            future = asyncio.get_running_loop().create_future()
+            queue.append((future, component_type, config))
+            return await future
+        
+        return await self._call_plugin(plugin_id, self.loaded_plugins[plugin_id], component_type, config)
+    
+    async def _call_plugin(self, plugin_id: str, plugin: PluginBase, component_type: str, config: Dict[str, Any]) -> Any:
+        """Call into a plugin, recording call statistics"""
+        stats = self.plugin_metrics.setdefault(plugin_id, PluginCallStats())
+        started_at = time.perf_counter()
+        try:
+            with self._plugin_output(plugin_id):
+                return await plugin.create_component(component_type, config)
+        except Exception:
+            stats.error_count += 1
+            raise
+        finally:
+            duration_seconds = time.perf_counter() - started_at
+            stats.total_calls += 1
+            stats.total_duration_us += int(duration_seconds * 1_000_000)
+            stats.last_call_unix_ms = int(time.time() * 1000)
+            self.plugin_call_durations.setdefault((plugin_id, component_type), Histogram()).observe(duration_seconds)
+    
+    def describe_plugin_api(self, plugin_id: str) -> Dict[str, Any]:
+        """Describe the components a loaded plugin provides and their parameter schemas"""
+        if plugin_id not in self.loaded_plugins:
+            raise ValueError(f"Plugin not loaded: {plugin_id}")
+        
+        plugin = self.loaded_plugins[plugin_id]
+        describe_api = getattr(plugin, "describe_api", None)
+        with self._plugin_output(plugin_id):
+            commands = describe_api() if describe_api else None
+        
+        # Plugins without their own description get one built from the manifest parameters
+        if commands is None:
+            parameter_schema = self._parameters_to_json_schema(self.plugin_manifests[plugin_id].get("parameters", {}))
+            commands = [
+                {
+                    "name": component_type,
+                    "description": f"Create a {component_type} component",
+                    "parameter_schema": parameter_schema,
+                    "response_schema": {"type": "object", "description": "torch.nn.Module"}
+                }
+                for component_type in plugin.get_neural_component_types()
+            ]
+        
+        return {"plugin_id": plugin_id, "commands": commands, "count": len(commands)}
+    
+    def _parameters_to_json_schema(self, parameters: Dict[str, Any]) -> Dict[str, Any]:
+        """Convert manifest parameter definitions to a JSON Schema object"""
+        properties = {}
+        for name, definition in parameters.items():
+            schema = {"type": PARAMETER_SCHEMA_TYPES.get(definition.get("type"), "string")}
+            if "min" in definition:
+                schema["minimum"] = definition["min"]
+            if "max" in definition:
+                schema["maximum"] = definition["max"]
+            if "values" in definition:
+                schema["enum"] = definition["values"]
+            if "default" in definition:
+                schema["default"] = definition["default"]
+            properties[name] = schema
+        
+        return {"type": "object", "properties": properties}
+    
+    async def clone_plugin_state(self, plugin_id: str, new_id: str) -> str:
+        """Register an independent copy of a loaded plugin's state under a new ID"""
+        if plugin_id not in self.loaded_plugins:
+            raise ValueError(f"Plugin not loaded: {plugin_id}")
+        
+        if not PLUGIN_ID_PATTERN.match(new_id):
+            raise ValueError(f"Invalid plugin id: {new_id}")
+        
+        if new_id in self.loaded_plugins or new_id in self.core_plugin_modules or new_id in self.discover_extension_plugins():
+            raise ValueError(f"Plugin id already in use: {new_id}")
+        
         plugin = self.loaded_plugins[plugin_id]
-        return await plugin.create_component(component_type, config)
+        manifest = dict(self.plugin_manifests[plugin_id], id=new_id)
+        self._register_call_graph(new_id, manifest)
+        logger = self._get_plugin_logger(new_id)
+        services = ScopedServiceLocator(self.services, lambda name: self._check_service_access(new_id, name))
+        
+        # Plugins may provide clone_state(); otherwise deep-copy everything but the shared backend handles.
+        # Every registered service is pinned, so a gpu_manager or config_store the plugin kept stays shared
+        if hasattr(plugin, "clone_state"):
+            with self._plugin_output(plugin_id):
+                clone = plugin.clone_state()
+        else:
+            shared = [self.services.request_service(name) for name in self.services.list_services()] + [self]
+            memo = {id(handle): handle for handle in shared}
+            memo.update({id(getattr(plugin, "logger", None)): logger, id(getattr(plugin, "services", None)): services})
+            clone = copy.deepcopy(plugin, memo)
+        clone.logger = logger
+        clone.services = services
+        
+        self.loaded_plugins[new_id] = clone
+        self.plugin_manifests[new_id] = manifest
+        self.plugin_clones[new_id] = plugin_id
+        if plugin_id in self.plugin_hashes:
+            self.plugin_hashes[new_id] = self.plugin_hashes[plugin_id]
+        if plugin_id in self.enabled_plugins:
+            self.enabled_plugins.add(new_id)
+        
+        logging.info(f"🧬 Cloned plugin {plugin_id} as {new_id}")
+        return new_id
+    
+    async def enable_ab_test(self, plugin_id: str, variant_id: str, traffic_split: float):
+        """Route a fraction of a plugin's calls to a variant plugin (e.g. a newer version or a clone)"""
+        for pid in (plugin_id, variant_id):
+            if pid not in self.loaded_plugins:
+                raise ValueError(f"Plugin not loaded: {pid}")
+        
+        if plugin_id == variant_id or variant_id in self.ab_tests:
+            raise ValueError(f"Invalid A/B test variant: {variant_id}")
+        
+        if not 0.0 <= traffic_split <= 1.0:
+            raise ValueError("traffic_split must be between 0 and 1")
+        
+        self.ab_tests[plugin_id] = {
+            "variant_id": variant_id,
+            "traffic_split": traffic_split,
+            "started_at": time.time(),
+            "arms": {arm: {"calls": 0, "errors": 0, "total_duration_ms": 0.0} for arm in (plugin_id, variant_id)}
+        }
+        logging.info(f"🧪 A/B test on {plugin_id}: {traffic_split:.0%} of calls to {variant_id}")
+    
+    async def _create_ab_test_component(self, plugin_id: str, component_type: str, config: Dict[str, Any]) -> Any:
+        """Dispatch to one A/B arm, chosen by a stable hash of the call parameters"""
+        ab_test = self.ab_tests[plugin_id]
+        call_key = json.dumps({"component_type": component_type, "config": config}, sort_keys=True, default=str)
+        bucket = int(hashlib.sha256(call_key.encode("utf-8")).hexdigest()[:8], 16) / 0xFFFFFFFF
+        target_id = ab_test["variant_id"] if bucket < ab_test["traffic_split"] else plugin_id
+        
+        arm = ab_test["arms"][target_id]
+        started_at = time.perf_counter()
+        try:
+            return await self._create_plugin_component(target_id, component_type, config)
+        except Exception:
+            arm["errors"] += 1
+            raise
+        finally:
+            arm["calls"] += 1
+            arm["total_duration_ms"] += (time.perf_counter() - started_at) * 1000
+    
+    def get_ab_test_report(self, plugin_id: str) -> Dict[str, Any]:
+        """Get call counts, error rates and average durations for each arm of an A/B test"""
+        if plugin_id not in self.ab_tests:
+            raise ValueError(f"No A/B test running for plugin: {plugin_id}")
+        
+        ab_test = self.ab_tests[plugin_id]
+        return {
+            "plugin_id": plugin_id,
+            "variant_id": ab_test["variant_id"],
+            "traffic_split": ab_test["traffic_split"],
+            "started_at": ab_test["started_at"],
+            "arms": {
+                arm_id: {
+                    "calls": arm["calls"],
+                    "error_rate": arm["errors"] / arm["calls"] if arm["calls"] else 0.0,
+                    "avg_duration_ms": arm["total_duration_ms"] / arm["calls"] if arm["calls"] else 0.0
+                }
+                for arm_id, arm in ab_test["arms"].items()
+            }
+        }
+    
+    async def disable_ab_test(self, plugin_id: str):
+        """Stop an A/B test, sending all calls back to the original plugin"""
+        if self.ab_tests.pop(plugin_id, None) is None:
+            raise ValueError(f"No A/B test running for plugin: {plugin_id}")
+        logging.info(f"Stopped A/B test on {plugin_id}")
+    
+    async def promote_ab_version(self, plugin_id: str, force: bool = False):
+        """End an A/B test by replacing the plugin with its variant under the original ID;
+        refuses while other loaded plugins depend on either arm unless forced"""
+        if plugin_id not in self.ab_tests:
+            raise ValueError(f"No A/B test running for plugin: {plugin_id}")
+        
+        variant_id = self.ab_tests[plugin_id]["variant_id"]
+        if not force:
+            for pid in (plugin_id, variant_id):
+                dependents = [d for d in self.get_loaded_plugin_dependency_closure(pid) if d not in (plugin_id, variant_id)]
+                if dependents:
+                    raise PluginDependentsLoaded(pid, dependents)
+        del self.ab_tests[plugin_id]
+        
+        for pid in (plugin_id, variant_id):
+            self._stop_scheduled_task(pid)
+            watcher = self.rollback_watchers.pop(pid, None)
+            if watcher:
+                watcher.cancel()
+        with self._plugin_output(plugin_id):
+            await self.loaded_plugins[plugin_id].cleanup()
+        
+        # The variant takes over the original ID: its logger, scoped services and call graph are re-keyed with it
+        promoted = self.loaded_plugins.pop(variant_id)
+        manifest = dict(self.plugin_manifests.pop(variant_id), id=plugin_id)
+        self.plugin_call_graphs.pop(variant_id, None)
+        self._register_call_graph(plugin_id, manifest)
+        promoted.logger = self._get_plugin_logger(plugin_id)
+        promoted.services = ScopedServiceLocator(self.services, lambda name: self._check_service_access(plugin_id, name))
+        
+        self.loaded_plugins[plugin_id] = promoted
+        self.plugin_manifests[plugin_id] = manifest
+        if variant_id in self.plugin_paths:
+            self.plugin_paths[plugin_id] = self.plugin_paths.pop(variant_id)
+        if variant_id in self.plugin_hashes:
+            self.plugin_hashes[plugin_id] = self.plugin_hashes.pop(variant_id)
+        if variant_id in self.plugin_module_overrides:
+            self.plugin_module_overrides[plugin_id] = self.plugin_module_overrides.pop(variant_id)
+        else:
+            self.plugin_module_overrides.pop(plugin_id, None)
+        self.enabled_plugins.discard(variant_id)
+        self.paused_plugins.discard(variant_id)
+        for future, _, _ in self.pending_calls.pop(variant_id, deque()):
+            if not future.done():
+                future.set_exception(RuntimeError(f"Plugin {variant_id} was promoted to {plugin_id}"))
+        self.plugin_clones.pop(variant_id, None)
+        for clone_id, source_id in self.plugin_clones.items():
+            if source_id == variant_id:
+                self.plugin_clones[clone_id] = plugin_id
+        self._start_scheduled_task(plugin_id)
+        
+        logging.info(f"⬆️ Promoted {variant_id} to {plugin_id}")
+    
+    async def pause_plugin(self, plugin_id: str):
+        """Pause a plugin, queueing further calls until it is resumed"""
+        if plugin_id not in self.loaded_plugins:
+            raise ValueError(f"Plugin not loaded: {plugin_id}")
+        
+        self.paused_plugins.add(plugin_id)
+        logging.info(f"⏸️ Paused plugin: {plugin_id}")
+    
+    async def resume_plugin(self, plugin_id: str):
+        """Resume a paused plugin and drain its queued calls"""
+        if plugin_id not in self.paused_plugins:
+            raise ValueError(f"Plugin not paused: {plugin_id}")
+        
+        self.paused_plugins.discard(plugin_id)
+        queue = self.pending_calls.pop(plugin_id, deque())
+        plugin = self.loaded_plugins.get(plugin_id)
+        
+        while queue:
+            future, component_type, config = queue.popleft()
+            if future.done():
+                continue
+            try:
+                if plugin is None:
+                    raise ValueError(f"Plugin {plugin_id} not loaded")
+                future.set_result(await self._call_plugin(plugin_id, plugin, component_type, config))
+            except Exception as e:
+                future.set_exception(e)
+        
+        logging.info(f"▶️ Resumed plugin: {plugin_id}")
     
     def get_loaded_plugins(self) -> List[str]:
         """Get list of loaded plugin IDs"""
@@ -313,18 +873,74 @@ class PluginManager:
     
     async def unload_all_plugins(self):
         """Unload all plugins"""
+        for watcher in self.rollback_watchers.values():
+            watcher.cancel()
+        self.rollback_watchers.clear()
+        
         for plugin_id in list(self.loaded_plugins.keys()):
-            await self.unload_plugin(plugin_id)
+            await self.unload_plugin(plugin_id, force=True)
         logging.info("All plugins unloaded")
     
-    async def unload_plugin(self, plugin_id: str):
-        """Unload specific plugin"""
+    def get_loaded_plugin_dependency_closure(self, plugin_id: str) -> List[str]:
+        """Get every loaded plugin that depends on plugin_id, directly or transitively"""
+        if plugin_id not in self.loaded_plugins:
+            raise ValueError(f"Plugin not loaded: {plugin_id}")
+        
+        dependents = []
+        pending = [plugin_id]
+        while pending:
+            current = pending.pop()
+            for candidate in self.loaded_plugins:
+                if candidate == plugin_id or candidate in dependents:
+                    continue
+                if current in self.plugin_manifests.get(candidate, {}).get("dependencies", []):
+                    dependents.append(candidate)
+                    pending.append(candidate)
+        return dependents
+    
+    async def unload_plugin(self, plugin_id: str, force: bool = False):
+        """Unload specific plugin; refuses while other loaded plugins depend on it or it is an arm of
+        an A/B test, unless forced, which also stops the test"""
+        if plugin_id in self.lazy_plugins and plugin_id not in self.loaded_plugins:
+            # Not loaded yet: forgetting it keeps its first call from loading it
+            del self.lazy_plugins[plugin_id]
+            logging.info(f"Unloaded lazy plugin before its first call: {plugin_id}")
+            return
+        
         if plugin_id in self.loaded_plugins:
-            plugin = self.loaded_plugins[plugin_id]
+            ab_test_ids = [pid for pid, test in self.ab_tests.items() if plugin_id in (pid, test["variant_id"])]
+            if not force:
+                dependents = self.get_loaded_plugin_dependency_closure(plugin_id)
+                if dependents:
+                    raise PluginDependentsLoaded(plugin_id, dependents)
+                if ab_test_ids:
+                    raise PluginInABTest(plugin_id, ab_test_ids[0])
+            
+            for test_plugin_id in ab_test_ids:
+                del self.ab_tests[test_plugin_id]
+                logging.info(f"Stopped A/B test on {test_plugin_id}: {plugin_id} was unloaded")
+            await self._teardown_plugin(plugin_id)
+    
+    async def _teardown_plugin(self, plugin_id: str):
+        """Clean up and forget a loaded plugin; reloads call this directly so A/B tests and history survive"""
+        plugin = self.loaded_plugins[plugin_id]
+        self._stop_scheduled_task(plugin_id)
+        watcher = self.rollback_watchers.pop(plugin_id, None)
+        if watcher and watcher is not asyncio.current_task():
+            watcher.cancel()
+        with self._plugin_output(plugin_id):
             await plugin.cleanup()
-            del self.loaded_plugins[plugin_id]
-            self.enabled_plugins.discard(plugin_id)
-            logging.info(f"Unloaded plugin: {plugin_id}")
+        del self.loaded_plugins[plugin_id]
+        
+        # Pause state survives a reload, but queued calls cannot be served
+        for future, _, _ in self.pending_calls.pop(plugin_id, deque()):
+            if not future.done():
+                future.set_exception(RuntimeError(f"Plugin {plugin_id} was unloaded"))
+        self.enabled_plugins.discard(plugin_id)
+        self.plugin_clones.pop(plugin_id, None)
+        self.plugin_call_graphs.pop(plugin_id, None)
+        self.plugin_module_overrides.pop(plugin_id, None)
+        logging.info(f"Unloaded plugin: {plugin_id}")
     
     async def enable_plugin(self, plugin_id: str):
         """Enable plugin"""
@@ -334,11 +950,978 @@ class PluginManager:
         else:
             raise ValueError(f"Plugin not loaded: {plugin_id}")
     
+    def get_plugin_load_order(self) -> LoadOrder:
+        """Order autoload plugins by startup_priority (lower first), then by dependencies"""
+        discovered = self.discover_extension_plugins()
+        manifests = {pid: entry["manifest"] for pid, entry in discovered.items() if entry["manifest"].get("autoload")}
+        order = LoadOrder()
+        
+        for plugin_id, manifest in list(manifests.items()):
+            environments = manifest.get("load_in_environments")
+            if environments is not None and self.environment not in environments:
+                order.skipped[plugin_id] = f"not loaded in the {self.environment} environment"
+                del manifests[plugin_id]
+        
+        return self._resolve_load_order(manifests, order)
+    
+    def _resolve_load_order(self, manifests: Dict[str, Dict[str, Any]], order: Optional[LoadOrder] = None) -> LoadOrder:
+        """Order plugins by startup_priority (lower first), then by dependencies among them or already loaded"""
+        order = order or LoadOrder()
+        
+        # A plugin never starts before its dependencies, even if its own priority is lower
+        effective: Dict[str, int] = {}
+        levels: Dict[str, int] = {}
+        
+        def resolve(plugin_id: str, visiting: Tuple[str, ...]) -> bool:
+            if plugin_id in effective:
+                return True
+            if plugin_id in order.skipped:
+                return False
+            if plugin_id in visiting:
+                cycle = " -> ".join(visiting[visiting.index(plugin_id):] + (plugin_id,))
+                order.skipped[plugin_id] = f"dependency cycle: {cycle}"
+                return False
+            
+            manifest = manifests[plugin_id]
+            priority = manifest.get("startup_priority", DEFAULT_STARTUP_PRIORITY)
+            dependencies = [d for d in manifest.get("dependencies", []) if d not in self.loaded_plugins]
+            for dependency in dependencies:
+                if dependency not in manifests:
+                    order.skipped[plugin_id] = f"dependency {dependency} is not loaded or autoloaded"
+                    return False
+                if not resolve(dependency, visiting + (plugin_id,)):
+                    order.skipped.setdefault(plugin_id, f"dependency {dependency} was skipped")
+                    return False
+                priority = max(priority, effective[dependency])
+            
+            effective[plugin_id] = priority
+            levels[plugin_id] = max(
+                (levels[d] + 1 for d in dependencies if effective[d] == priority), default=0
+            )
+            return True
+        
+        for plugin_id in manifests:
+            resolve(plugin_id, ())
+        
+        grouped: Dict[Tuple[int, int], List[str]] = {}
+        for plugin_id in effective:
+            grouped.setdefault((effective[plugin_id], levels[plugin_id]), []).append(plugin_id)
+        
+        order.groups = [sorted(grouped[key]) for key in sorted(grouped)]
+        order.priorities = {pid: manifests[pid].get("startup_priority", DEFAULT_STARTUP_PRIORITY) for pid in effective}
+        return order
+    
+    async def load_autoload_plugins(self) -> LoadOrder:
+        """Load extension plugins marked "autoload" in their manifest, in resolved priority order"""
+        order = self.get_plugin_load_order()
+        discovered = self.discover_extension_plugins()
+        self.autoload_plugins = [
+            pid for group in order.groups for pid in group if not discovered[pid]["manifest"].get("lazy")
+        ]
+        
+        for plugin_id, reason in order.skipped.items():
+            logging.warning(f"⚠️ Skipping autoload plugin {plugin_id}: {reason}")
+        
+        async def load(plugin_id: str):
+            manifest = discovered[plugin_id]["manifest"]
+            if manifest.get("lazy"):
+                self.lazy_plugins[plugin_id] = discovered[plugin_id]["path"]
+                self.plugin_manifests[plugin_id] = manifest
+                logging.info(f"💤 Deferred lazy plugin {plugin_id} until first use")
+                return
+            try:
+                await self.load_plugin_from_file(discovered[plugin_id]["path"])
+                await self.enable_plugin(plugin_id)
+            except Exception as e:
+                logging.error(f"❌ Failed to autoload plugin {plugin_id}: {e}")
+        
+        for group in order.groups:
+            await asyncio.gather(*(load(pid) for pid in group if pid not in self.loaded_plugins))
+        
+        logging.info(f"✅ Autoloaded {sum(pid in self.loaded_plugins for pid in self.autoload_plugins)}/{len(self.autoload_plugins)} extension plugins")
+        return order
+    
+    async def materialize_lazy_plugin(self, plugin_id: str):
+        """Load a deferred lazy plugin now; concurrent first calls share one load"""
+        lock = self._lazy_load_locks.setdefault(plugin_id, asyncio.Lock())
+        async with lock:
+            if plugin_id not in self.lazy_plugins:
+                return
+            
+            started_at = time.perf_counter()
+            await self.load_plugin_from_file(self.lazy_plugins[plugin_id])
+            await self.enable_plugin(plugin_id)
+            del self.lazy_plugins[plugin_id]
+            logging.info(f"✅ Loaded lazy plugin {plugin_id} in {(time.perf_counter() - started_at) * 1000:.0f}ms")
+    
+    async def materialize_all_lazy_plugins(self) -> List[str]:
+        """Load every deferred lazy plugin, e.g. to warm up before a latency-sensitive session"""
+        plugin_ids = list(self.lazy_plugins)
+        for plugin_id in plugin_ids:
+            await self.materialize_lazy_plugin(plugin_id)
+        return plugin_ids
+    
+    def discover_extension_plugins(self) -> Dict[str, Dict[str, Any]]:
+        """Scan the extension directory for plugin manifests without loading them"""
+        discovered = {}
+        
+        for manifest_path in sorted(self.extension_plugin_directory.glob("*/manifest.json")):
+            try:
+                with open(manifest_path, 'r') as f:
+                    manifest = json.load(f)
+                plugin_id = manifest["id"]
+                if not PLUGIN_ID_PATTERN.match(plugin_id) and not is_legacy_plugin_id(plugin_id):
+                    raise ValueError(f"plugin id '{plugin_id}' must have the form vendor.plugin_name")
+                if plugin_id in discovered or plugin_id in self.core_plugin_modules:
+                    raise ValueError(f"plugin id '{plugin_id}' is already used by another plugin")
+                priority = manifest.get("startup_priority", DEFAULT_STARTUP_PRIORITY)
+                try:
+                    manifest["startup_priority"] = int(priority)
+                except (TypeError, ValueError):
+                    logging.warning(f"⚠️ Plugin {plugin_id} has a non-numeric startup_priority {priority!r}; "
+                                    f"using {DEFAULT_STARTUP_PRIORITY}")
+                    manifest["startup_priority"] = DEFAULT_STARTUP_PRIORITY
+                module_path = manifest_path.parent / manifest.get("main_module", "plugin.py")
+                sha256_hash = hashlib.sha256(module_path.read_bytes()).hexdigest() if module_path.exists() else ""
+                discovered[plugin_id] = {"path": manifest_path.parent, "manifest": manifest, "sha256_hash": sha256_hash}
+            except Exception as e:
+                logging.warning(f"⚠️ Skipping invalid plugin manifest {manifest_path}: {e}")
+                continue
+            
+            # A changed module without a version bump is still a new version of the plugin
+            loaded_hash = self.plugin_hashes.get(plugin_id)
+            if loaded_hash and loaded_hash != sha256_hash:
+                discovered[plugin_id]["modified"] = True
+                loaded_version = self.plugin_manifests.get(plugin_id, {}).get("version")
+                if loaded_version == manifest.get("version"):
+                    logging.warning(f"⚠️ PluginHashMismatch: {plugin_id} changed on disk but version is still {loaded_version}")
+        
+        return discovered
+    
+    def _check_extension_plugin(self, plugin_id: str, plugin_path: Path, manifest: Dict[str, Any]):
+        """Reject extension plugins with un-namespaced or colliding IDs, or an unsupported API version"""
+        if is_legacy_plugin_id(plugin_id):
+            logging.warning(f"⚠️ Plugin id '{plugin_id}' is not namespaced; un-namespaced ids are deprecated, "
+                            f"rename it to the form vendor.plugin_name")
+        elif not PLUGIN_ID_PATTERN.match(plugin_id):
+            raise ValueError(f"Invalid plugin id '{plugin_id}': expected the form vendor.plugin_name")
+        
+        if plugin_id in self.core_plugin_modules:
+            raise ValueError(f"Plugin id '{plugin_id}' collides with a core plugin")
+        
+        required_api_version = manifest.get("required_api_version", 1)
+        if required_api_version > PLUGIN_API_VERSION:
+            raise ValueError(f"Plugin '{plugin_id}' requires plugin API version {required_api_version}, "
+                             f"but this backend provides version {PLUGIN_API_VERSION}")
+        
+        loaded_path = self.plugin_paths.get(plugin_id)
+        if plugin_id in self.loaded_plugins and loaded_path is not None and Path(loaded_path).resolve() != Path(plugin_path).resolve():
+            raise ValueError(f"Plugin id '{plugin_id}' is already loaded from {loaded_path}")
+    
+    async def dry_run_load(self, plugin_id: str) -> DryRunReport:
+        """Validate that a plugin would load, without importing or executing its code"""
+        discovered = self.discover_extension_plugins()
+        if plugin_id not in discovered:
+            raise ValueError(f"Plugin {plugin_id} not found")
+        
+        plugin_path = discovered[plugin_id]["path"]
+        manifest = discovered[plugin_id]["manifest"]
+        report = DryRunReport(plugin_id=plugin_id)
+        
+        # Manifest and main module: parse the source to find the plugin class
+        for key in ["id", "name", "version"]:
+            if key not in manifest:
+                report.manifest_errors.append(f"Missing manifest field: {key}")
+        
+        module_path = plugin_path / manifest.get("main_module", "plugin.py")
+        plugin_class_name = manifest.get("plugin_class", "Plugin")
+        if not module_path.exists():
+            report.manifest_errors.append(f"Plugin main module not found: {module_path.name}")
+        else:
+            try:
+                tree = ast.parse(module_path.read_text(encoding='utf-8'))
+                class_names = {node.name for node in ast.walk(tree) if isinstance(node, ast.ClassDef)}
+                if plugin_class_name not in class_names:
+                    report.manifest_errors.append(f"Plugin class not found: {plugin_class_name}")
+            except SyntaxError as e:
+                report.manifest_errors.append(f"Plugin main module has a syntax error: {e}")
+        
+        report.manifest_valid = not report.manifest_errors
+        
+        # Signature: unsigned plugins pass, signed ones must match the module hash
+        expected_hash = manifest.get("sha256")
+        if expected_hash is None:
+            report.signature_valid = True
+        elif module_path.exists():
+            report.signature_valid = hashlib.sha256(module_path.read_bytes()).hexdigest() == expected_hash
+        
+        # Dependencies may be other plugins or importable Python packages. Plugin ids are checked first:
+        # find_spec imports a dotted name's parent packages and raises when they don't exist
+        for dependency in manifest.get("dependencies", []):
+            if dependency in self.loaded_plugins or dependency in discovered:
+                continue
+            try:
+                if importlib.util.find_spec(dependency) is not None:
+                    continue
+            except (ImportError, ValueError):
+                pass
+            report.missing_dependencies.append(dependency)
+        report.dependencies_satisfied = not report.missing_dependencies
+        
+        available_services = self.services.list_services()
+        report.missing_services = [s for s in manifest.get("required_services", []) if s not in available_services]
+        report.required_services_available = not report.missing_services
+        
+        report.estimated_memory_mb = int(manifest.get("estimated_memory_mb", 0))
+        return report
+    
+    def test_plugin_compatibility(self, plugin_a: str, plugin_b: str) -> CompatibilityReport:
+        """Check two loaded or discovered plugins for conflicts before deploying them together"""
+        discovered = self.discover_extension_plugins()
+        
+        def manifest_for(plugin_id: str) -> Dict[str, Any]:
+            if plugin_id in self.plugin_manifests:
+                return self.plugin_manifests[plugin_id]
+            if plugin_id in discovered:
+                return discovered[plugin_id]["manifest"]
+            raise ValueError(f"Plugin {plugin_id} not found")
+        
+        manifest_a, manifest_b = manifest_for(plugin_a), manifest_for(plugin_b)
+        report = CompatibilityReport(plugin_a, plugin_b)
+        
+        # Shared component names make create_neural_component ambiguous for clients
+        components_a = set(manifest_a.get("neural_components", [])) | set(manifest_a.get("call_graph", {}).get("registered_commands", []))
+        components_b = set(manifest_b.get("neural_components", [])) | set(manifest_b.get("call_graph", {}).get("registered_commands", []))
+        for name in sorted(components_a & components_b):
+            report.issues.append(f"Both plugins provide component '{name}'")
+        
+        combined_mb = int(manifest_a.get("estimated_memory_mb", 0)) + int(manifest_b.get("estimated_memory_mb", 0))
+        available_mb = psutil.virtual_memory().available // (1024 * 1024)
+        if combined_mb > available_mb:
+            report.issues.append(f"Combined estimated memory {combined_mb}MB exceeds available {available_mb}MB")
+        
+        for plugin_id, manifest in [(plugin_a, manifest_a), (plugin_b, manifest_b)]:
+            if manifest.get("required_api_version", 1) > PLUGIN_API_VERSION:
+                report.issues.append(f"{plugin_id} requires plugin API version {manifest['required_api_version']}, "
+                                     f"backend provides {PLUGIN_API_VERSION}")
+        
+        if manifest_a.get("call_graph", {}).get("exclusive_gpu") and manifest_b.get("call_graph", {}).get("exclusive_gpu"):
+            report.issues.append("Both plugins declare exclusive GPU access")
+        
+        report.compatible = not report.issues
+        return report
+    
+    async def run_plugin_self_test(self, plugin_id: str) -> TestReport:
+        """Run a loaded plugin's self-test, if it provides one"""
+        if plugin_id not in self.loaded_plugins:
+            raise ValueError(f"Plugin not loaded: {plugin_id}")
+        
+        plugin = self.loaded_plugins[plugin_id]
+        run_self_test = getattr(plugin, "run_self_test", None)
+        
+        started_at = time.perf_counter()
+        try:
+            with self._plugin_output(plugin_id):
+                result = await run_self_test() if run_self_test else None
+        except Exception as e:
+            result = (False, f"Self-test raised: {e}")
+        duration_ms = int((time.perf_counter() - started_at) * 1000)
+        
+        if result is None:
+            return TestReport(plugin_id, True, "Plugin does not provide a self-test", duration_ms, skipped=True)
+        
+        passed, message = result
+        self.plugin_metrics.setdefault(plugin_id, PluginCallStats()).self_test_passed = bool(passed)
+        if passed:
+            logging.info(f"✅ Self-test passed for plugin {plugin_id} ({duration_ms}ms)")
+        else:
+            logging.warning(f"⚠️ Self-test failed for plugin {plugin_id}: {message}")
+        return TestReport(plugin_id, bool(passed), str(message), duration_ms)
+    
+    def get_plugin_metrics(self) -> Dict[str, Dict[str, Any]]:
+        """Get call statistics for every plugin that has been called"""
+        return {plugin_id: asdict(stats) for plugin_id, stats in self.plugin_metrics.items()}
+    
+    def reset_plugin_metrics(self):
+        """Clear all plugin call statistics, including those of unloaded plugins"""
+        self.plugin_metrics.clear()
+        self.plugin_call_durations.clear()
+        logging.info("🧹 Reset plugin metrics")
+    
+    async def run_all_self_tests(self) -> List[TestReport]:
+        """Run self-tests for every loaded plugin"""
+        return [await self.run_plugin_self_test(plugin_id) for plugin_id in list(self.loaded_plugins)]
+    
+    async def load_group(self, group_name: str) -> List[str]:
+        """Load every plugin in a group, rolling back if any member fails"""
+        discovered = self.discover_extension_plugins()
+        members = [pid for pid, entry in discovered.items() if entry["manifest"].get("group") == group_name]
+        
+        if not members:
+            raise ValueError(f"Plugin group not found: {group_name}")
+        
+        order = self._resolve_load_order({pid: discovered[pid]["manifest"] for pid in members})
+        if order.skipped:
+            plugin_id, reason = next(iter(order.skipped.items()))
+            raise ValueError(f"Failed to load plugin group {group_name}: cannot load {plugin_id}: {reason}")
+        members = [pid for group in order.groups for pid in group]
+        
+        newly_loaded = []
+        try:
+            for plugin_id in members:
+                if plugin_id in self.loaded_plugins:
+                    continue
+                await self.load_plugin_from_file(discovered[plugin_id]["path"])
+                newly_loaded.append(plugin_id)
+                await self.enable_plugin(plugin_id)
+        except Exception as e:
+            logging.error(f"❌ Failed to load plugin group {group_name}, rolling back: {e}")
+            for plugin_id in reversed(newly_loaded):
+                await self.unload_plugin(plugin_id)
+            raise ValueError(f"Failed to load plugin group {group_name}: {e}")
+        
+        logging.info(f"✅ Loaded plugin group {group_name}: {members}")
+        return members
+    
+    async def unload_group(self, group_name: str) -> List[str]:
+        """Unload every loaded plugin in a group, reloading them if any member fails"""
+        members = [pid for pid in self.loaded_plugins if self.plugin_manifests.get(pid, {}).get("group") == group_name]
+        
+        if not members:
+            raise ValueError(f"No loaded plugins in group: {group_name}")
+        
+        # Members may depend on each other, but nothing outside the group may depend on them
+        for plugin_id in members:
+            outside = [d for d in self.get_loaded_plugin_dependency_closure(plugin_id) if d not in members]
+            if outside:
+                raise PluginDependentsLoaded(plugin_id, outside)
+        
+        # Dependents go first: members are in load order
+        unloaded = []
+        try:
+            for plugin_id in reversed(members):
+                path = self.plugin_paths.get(plugin_id)
+                was_enabled = plugin_id in self.enabled_plugins
+                await self.unload_plugin(plugin_id, force=True)
+                unloaded.append((plugin_id, path, was_enabled))
+        except Exception as e:
+            logging.error(f"❌ Failed to unload plugin group {group_name}, rolling back: {e}")
+            for plugin_id, path, was_enabled in reversed(unloaded):
+                if path is not None:
+                    await self.load_plugin_from_file(path)
+                    if was_enabled:
+                        await self.enable_plugin(plugin_id)
+            raise ValueError(f"Failed to unload plugin group {group_name}: {e}")
+        
+        logging.info(f"Unloaded plugin group {group_name}: {members}")
+        return members
+    
+    def get_plugin_module_path(self, plugin_id: str) -> Path:
+        """Get the main Python module file of a plugin"""
+        if plugin_id in self.plugin_module_overrides:
+            return self.plugin_module_overrides[plugin_id]
+        
+        if plugin_id in self.plugin_paths:
+            manifest = self.plugin_manifests.get(plugin_id, {})
+            return self.plugin_paths[plugin_id] / manifest.get("main_module", "plugin.py")
+        
+        if plugin_id in self.core_plugin_modules:
+            module_path, _ = self.core_plugin_modules[plugin_id]
+            spec = importlib.util.find_spec(module_path)
+            if spec is not None and spec.origin:
+                return Path(spec.origin)
+        
+        raise ValueError(f"Plugin {plugin_id} not found")
+    
+    def _record_plugin_hash(self, plugin_id: str):
+        """Store the SHA-256 of a plugin's main module at load time"""
+        try:
+            module_path = self.get_plugin_module_path(plugin_id)
+            self.plugin_hashes[plugin_id] = hashlib.sha256(module_path.read_bytes()).hexdigest()
+        except Exception as e:
+            logging.warning(f"⚠️ Could not hash plugin {plugin_id}: {e}")
+    
+    def _read_plugin_lock(self) -> Dict[str, Dict[str, str]]:
+        """Read the locked plugin entries, or an empty dict when there is no lock file"""
+        if not self.lock_file_path.exists():
+            return {}
+        with open(self.lock_file_path, 'r') as f:
+            return json.load(f).get("plugins", {})
+    
+    def _write_plugin_lock(self, entries: Dict[str, Dict[str, str]]):
+        """Write the lock file atomically"""
+        temp_path = self.lock_file_path.with_suffix(".lock.tmp")
+        with open(temp_path, 'w') as f:
+            json.dump({"version": 1, "plugins": entries}, f, indent=2, sort_keys=True)
+        temp_path.replace(self.lock_file_path)
+    
+    def _verify_plugin_lock(self, plugin_id: str, manifest: Dict[str, Any], module_path: Path):
+        """Refuse to load an extension plugin that differs from its locked version or hash"""
+        locked = self._read_plugin_lock().get(plugin_id)
+        if locked is None:
+            return
+        
+        version = manifest.get("version", "")
+        if locked["version"] != version:
+            raise ValueError(f"Plugin {plugin_id} version {version} does not match locked version {locked['version']}")
+        
+        sha256_hash = hashlib.sha256(module_path.read_bytes()).hexdigest()
+        if locked["sha256_hash"] != sha256_hash:
+            raise ValueError(f"Plugin {plugin_id} hash {sha256_hash[:12]} does not match locked hash {locked['sha256_hash'][:12]}")
+    
+    def update_plugin_lock(self) -> Dict[str, Dict[str, str]]:
+        """Regenerate the lock file from the currently loaded extension plugins"""
+        entries = {
+            plugin_id: {
+                "version": self.plugin_manifests[plugin_id].get("version", ""),
+                "sha256_hash": self.plugin_hashes.get(plugin_id, "")
+            }
+            for plugin_id in self.plugin_paths
+            if plugin_id in self.loaded_plugins and plugin_id not in self.plugin_clones
+        }
+        self._write_plugin_lock(entries)
+        
+        logging.info(f"🔒 Wrote plugin lock with {len(entries)} plugins")
+        return entries
+    
+    def check_plugin_lock(self) -> Dict[str, Any]:
+        """Compare loaded extension plugins against the lock file without changing anything"""
+        locked = self._read_plugin_lock()
+        mismatches = []
+        unlocked = []
+        
+        for plugin_id in self.plugin_paths:
+            if plugin_id not in self.loaded_plugins or plugin_id in self.plugin_clones:
+                continue
+            if plugin_id not in locked:
+                unlocked.append(plugin_id)
+                continue
+            
+            version = self.plugin_manifests[plugin_id].get("version", "")
+            try:
+                current_hash = hashlib.sha256(self.get_plugin_module_path(plugin_id).read_bytes()).hexdigest()
+            except OSError:
+                current_hash = ""
+            
+            if locked[plugin_id]["version"] != version or locked[plugin_id]["sha256_hash"] != current_hash:
+                mismatches.append({
+                    "plugin_id": plugin_id,
+                    "locked_version": locked[plugin_id]["version"],
+                    "version": version,
+                    "locked_sha256_hash": locked[plugin_id]["sha256_hash"],
+                    "sha256_hash": current_hash
+                })
+        
+        return {
+            "lock_file_exists": self.lock_file_path.exists(),
+            "matches": not mismatches,
+            "mismatches": mismatches,
+            "unlocked_plugins": unlocked
+        }
+    
+    def _get_history_directory(self, plugin_id: str) -> Path:
+        """Get the directory holding a plugin's previous versions"""
+        return self.plugin_paths[plugin_id] / ".history"
+    
+    def _archive_plugin_version(self, plugin_id: str):
+        """Keep a copy of the loaded main module, named by its hash, for later rollback"""
+        sha256_hash = self.plugin_hashes.get(plugin_id)
+        if plugin_id not in self.plugin_paths or not sha256_hash:
+            return
+        
+        module_path = self.get_plugin_module_path(plugin_id)
+        history_directory = self._get_history_directory(plugin_id)
+        history_directory.mkdir(exist_ok=True)
+        
+        archived_path = history_directory / f"{sha256_hash}{module_path.suffix}"
+        if not archived_path.exists():
+            shutil.copy2(module_path, archived_path)
+        archived_path.touch()  # newest version sorts last
+        
+        # The current version plus version_history_count previous ones; tagged versions are kept
+        tagged_hashes = set(self.list_plugin_tags(plugin_id).values())
+        versions = sorted(history_directory.iterdir(), key=lambda p: p.stat().st_mtime)
+        for old_path in versions[:-(self.version_history_count + 1)]:
+            if old_path.stem not in tagged_hashes:
+                old_path.unlink()
+    
+    def get_rollback_versions(self, plugin_id: str) -> List[str]:
+        """List hashes of previous plugin versions, newest first"""
+        if plugin_id not in self.plugin_paths:
+            return []
+        
+        history_directory = self._get_history_directory(plugin_id)
+        if not history_directory.exists():
+            return []
+        
+        versions = sorted(history_directory.iterdir(), key=lambda p: p.stat().st_mtime, reverse=True)
+        return [p.stem for p in versions if p.stem != self.plugin_hashes.get(plugin_id)]
+    
+    async def rollback_plugin(self, plugin_id: str, steps_back: int = 1) -> str:
+        """Restore a previous version of an extension plugin and reload it; returns the restored hash"""
+        if plugin_id not in self.plugin_paths:
+            raise ValueError(f"No rollback history for plugin: {plugin_id}")
+        
+        versions = self.get_rollback_versions(plugin_id)
+        if steps_back < 1 or steps_back > len(versions):
+            raise ValueError(f"Plugin {plugin_id} has {len(versions)} previous versions, cannot go back {steps_back}")
+        
+        target_hash = versions[steps_back - 1]
+        await self._restore_plugin_version(plugin_id, target_hash)
+        
+        logging.info(f"⏪ Rolled back plugin {plugin_id} to {target_hash[:12]}")
+        return target_hash
+    
+    async def reload_plugin(self, plugin_id: str) -> Dict[str, Any]:
+        """Reload an extension plugin from disk, rolling back if the new version fails its checks"""
+        if plugin_id not in self.plugin_paths or plugin_id not in self.loaded_plugins:
+            raise ValueError(f"Extension plugin not loaded: {plugin_id}")
+        
+        previous_hash = self.plugin_hashes.get(plugin_id)
+        was_enabled = plugin_id in self.enabled_plugins
+        watcher = self.rollback_watchers.pop(plugin_id, None)
+        if watcher:
+            watcher.cancel()
+        
+        await self._teardown_plugin(plugin_id)
+        try:
+            await self.load_plugin_from_file(self.plugin_paths[plugin_id])
+        except Exception as e:
+            reason = f"Load failed: {e}"
+            return await self._rollback_report(plugin_id, previous_hash, reason, was_enabled)
+        if was_enabled:
+            await self.enable_plugin(plugin_id)
+        
+        new_hash = self.plugin_hashes.get(plugin_id)
+        report = await self.run_plugin_self_test(plugin_id)
+        if not report.passed and new_hash != previous_hash:
+            reason = f"Self-test failed: {report.message}"
+            return await self._rollback_report(plugin_id, previous_hash, reason, was_enabled)
+        
+        if new_hash != previous_hash:
+            self.rollback_watchers[plugin_id] = asyncio.create_task(
+                self._watch_reloaded_plugin(plugin_id, previous_hash, was_enabled)
+            )
+        
+        self.rollback_failures.pop(plugin_id, None)
+        logging.info(f"🔄 Reloaded plugin {plugin_id} ({(new_hash or '')[:12]})")
+        return {"status": "reloaded", "sha256_hash": new_hash, "rollback_window_ms": self.rollback_window_ms}
+    
+    async def _rollback_report(self, plugin_id: str, previous_hash: Optional[str], reason: str, was_enabled: bool) -> Dict[str, Any]:
+        """reload_plugin's result after the new version failed its checks"""
+        if await self._auto_rollback(plugin_id, previous_hash, reason, was_enabled):
+            return {"status": "rolled_back", "reason": reason, "sha256_hash": previous_hash}
+        return {"status": "rollback_failed", "reason": reason, "error": self.rollback_failures[plugin_id]}
+    
+    async def _watch_reloaded_plugin(self, plugin_id: str, previous_hash: str, was_enabled: bool):
+        """Poll a reloaded plugin's health check until the rollback window closes"""
+        deadline = time.monotonic() + self.rollback_window_ms / 1000
+        interval_seconds = min(1.0, self.rollback_window_ms / 1000)
+        
+        try:
+            while time.monotonic() < deadline:
+                await asyncio.sleep(interval_seconds)
+                health_check = getattr(self.loaded_plugins.get(plugin_id), "health_check", None)
+                if health_check is None:
+                    break
+                
+                try:
+                    with self._plugin_output(plugin_id):
+                        healthy = await health_check()
+                except Exception as e:
+                    healthy, error = False, e
+                else:
+                    error = None
+                
+                if healthy is False:
+                    self.rollback_watchers.pop(plugin_id, None)
+                    reason = f"Health check failed: {error}" if error else "Health check reported unhealthy"
+                    await self._auto_rollback(plugin_id, previous_hash, reason, was_enabled)
+                    return
+            
+            self.rollback_watchers.pop(plugin_id, None)
+            logging.info(f"✅ Plugin {plugin_id} stayed healthy through the rollback window")
+        except asyncio.CancelledError:
+            pass
+    
+    async def _auto_rollback(self, plugin_id: str, previous_hash: Optional[str], reason: str, was_enabled: bool) -> bool:
+        """Run the version that was loaded before reload_plugin again and announce it; returns False when the
+        previous version could not be loaded, which is logged and recorded in the plugin's info"""
+        logging.warning(f"⚠️ Rolling back plugin {plugin_id}: {reason}")
+        if not previous_hash:
+            return self._record_rollback_failure(plugin_id, reason, "no previous version to roll back to")
+        
+        archived_path = self._get_archived_version_path(plugin_id, previous_hash)
+        if not archived_path.exists():
+            return self._record_rollback_failure(plugin_id, reason, f"version {previous_hash[:12]} is no longer in its history")
+        
+        # Load the archived module directly: the source file keeps the user's edits and the lock stays
+        # as it was, since an automatic rollback is not a choice of version
+        try:
+            if plugin_id in self.loaded_plugins:  # not loaded when the new version failed to load
+                await self._teardown_plugin(plugin_id)
+            await self.load_plugin_from_file(self.plugin_paths[plugin_id], module_path=archived_path)
+        except Exception as e:
+            return self._record_rollback_failure(plugin_id, reason, f"loading version {previous_hash[:12]} failed: {e}")
+        
+        self.rollback_failures.pop(plugin_id, None)
+        logging.warning(f"⚠️ Plugin {plugin_id} is running archived version {previous_hash[:12]}; "
+                        f"{self.plugin_paths[plugin_id]} still holds the failed version")
+        if was_enabled:
+            await self.enable_plugin(plugin_id)
+        
+        broadcast_channel = self.services.request_service("broadcast_channel")
+        if broadcast_channel:
+            await broadcast_channel.broadcast_json({
+                "event": "RolledBackPlugin",
+                "plugin_id": plugin_id,
+                "reason": reason,
+                "sha256_hash": previous_hash
+            })
+        return True
+    
+    def _record_rollback_failure(self, plugin_id: str, reason: str, error: str) -> bool:
+        logging.error(f"❌ Could not roll back plugin {plugin_id} ({reason}): {error}")
+        self.rollback_failures[plugin_id] = error
+        return False
+    
+    def _get_archived_version_path(self, plugin_id: str, sha256_hash: str) -> Path:
+        """Get the history file holding a plugin version"""
+        return self._get_history_directory(plugin_id) / f"{sha256_hash}{self.get_plugin_module_path(plugin_id).suffix}"
+    
+    async def _restore_plugin_version(self, plugin_id: str, target_hash: str):
+        """Replace a loaded extension plugin's main module with an archived version and reload it"""
+        archived_path = self._get_archived_version_path(plugin_id, target_hash)
+        if not archived_path.exists():
+            raise ValueError(f"Version {target_hash[:12]} of plugin {plugin_id} is no longer in its history")
+        
+        self.plugin_module_overrides.pop(plugin_id, None)  # an explicit restore replaces the source file itself
+        module_path = self.get_plugin_module_path(plugin_id)
+        plugin_path = self.plugin_paths[plugin_id]
+        
+        await self._teardown_plugin(plugin_id)  # reloaded straight away
+        shutil.copy2(archived_path, module_path)
+        
+        # Restoring is an explicit choice of version, so re-pin a locked plugin to it
+        locked = self._read_plugin_lock()
+        if plugin_id in locked:
+            locked[plugin_id]["sha256_hash"] = target_hash
+            self._write_plugin_lock(locked)
+        
+        await self.load_plugin_from_file(plugin_path)
+    
+    def _read_plugin_registry(self) -> Dict[str, Any]:
+        """Read plugin_registry.json, or an empty registry when it does not exist"""
+        if not self.registry_path.exists():
+            return {"tags": {}}
+        with open(self.registry_path, 'r') as f:
+            return json.load(f)
+    
+    def _write_plugin_registry(self, registry: Dict[str, Any]):
+        """Write plugin_registry.json atomically"""
+        temp_path = self.registry_path.with_suffix(".json.tmp")
+        with open(temp_path, 'w') as f:
+            json.dump(registry, f, indent=2, sort_keys=True)
+        temp_path.replace(self.registry_path)
+    
+    def list_plugin_tags(self, plugin_id: str) -> Dict[str, str]:
+        """Get a plugin's release tags, mapped to version hashes"""
+        return self._read_plugin_registry().get("tags", {}).get(plugin_id, {})
+    
+    def tag_plugin_version(self, plugin_id: str, tag: str, sha256_hash: Optional[str] = None) -> str:
+        """Tag a version from a plugin's history, the loaded one by default; returns the tagged hash"""
+        if plugin_id not in self.plugin_paths:
+            raise ValueError(f"No version history for plugin: {plugin_id}")
+        
+        sha256_hash = sha256_hash or self.plugin_hashes.get(plugin_id, "")
+        if not sha256_hash or not self._get_archived_version_path(plugin_id, sha256_hash).exists():
+            raise ValueError(f"Version {sha256_hash[:12]} of plugin {plugin_id} is not in its history")
+        
+        registry = self._read_plugin_registry()
+        registry.setdefault("tags", {}).setdefault(plugin_id, {})[tag] = sha256_hash
+        self._write_plugin_registry(registry)
+        
+        logging.info(f"🏷️ Tagged plugin {plugin_id} version {sha256_hash[:12]} as {tag}")
+        return sha256_hash
+    
+    async def load_plugin_by_tag(self, plugin_id: str, tag: str) -> str:
+        """Restore the plugin version a tag points to and reload it; returns the restored hash"""
+        if plugin_id not in self.plugin_paths:
+            raise ValueError(f"No version history for plugin: {plugin_id}")
+        
+        target_hash = self.list_plugin_tags(plugin_id).get(tag)
+        if target_hash is None:
+            raise ValueError(f"Plugin {plugin_id} has no tag {tag}")
+        
+        await self._restore_plugin_version(plugin_id, target_hash)
+        
+        logging.info(f"🏷️ Loaded plugin {plugin_id} at tag {tag} ({target_hash[:12]})")
+        return target_hash
+    
+    async def export_plugin(self, plugin_id: str, dest: Path) -> Path:
+        """Export a loaded plugin's files and resolved manifest to a ZIP archive"""
+        if plugin_id not in self.loaded_plugins:
+            raise ValueError(f"Plugin not loaded: {plugin_id}")
+        
+        if plugin_id in self.paused_plugins:
+            raise ValueError(f"Cannot export paused plugin: {plugin_id}")
+        
+        module_path = self.get_plugin_module_path(plugin_id)
+        plugin_info = await self.get_plugin_info(plugin_id)
+        plugin_info["sha256"] = plugin_info["sha256_hash"]
+        
+        dest = Path(dest)
+        dest.parent.mkdir(parents=True, exist_ok=True)
+        
+        with zipfile.ZipFile(dest, 'w', zipfile.ZIP_DEFLATED) as archive:
+            if plugin_id in self.plugin_paths:
+                # Extension plugin: the whole plugin directory, including manifest.json and plugin.sig
+                plugin_path = self.plugin_paths[plugin_id]
+                for file_path in sorted(plugin_path.rglob("*")):
+                    if file_path.is_file() and "__pycache__" not in file_path.parts and ".history" not in file_path.parts:
+                        archive.write(file_path, file_path.relative_to(plugin_path))
+            else:
+                archive.write(module_path, module_path.name)
+            
+            archive.writestr("MANIFEST.json", json.dumps(plugin_info, indent=2, default=str))
+        
+        logging.info(f"📦 Exported plugin {plugin_id} to {dest}")
+        return dest
+    
+    def get_plugin_log_path(self, plugin_id: str) -> Path:
+        """Get the log file path for a plugin"""
+        return self.log_directory / f"{plugin_id}.log"
+    
+    def _get_plugin_logger(self, plugin_id: str) -> logging.Logger:
+        """Get a logger that writes only to the plugin's own rotating log file"""
+        logger = logging.getLogger(f"archetype.plugins.{plugin_id}")
+        
+        if not logger.handlers:
+            handler = logging.handlers.RotatingFileHandler(
+                self.get_plugin_log_path(plugin_id), maxBytes=self.log_max_bytes, backupCount=3
+            )
+            handler.setFormatter(logging.Formatter(
+                fmt='%(asctime)s - %(name)s - %(levelname)s - %(message)s',
+                datefmt='%Y-%m-%d %H:%M:%S'
+            ))
+            logger.addHandler(handler)
+            logger.setLevel(logging.DEBUG)
+            logger.propagate = False
+        
+        return logger
+    
+    def set_log_max_bytes(self, log_max_bytes: int):
+        """Change the rotation size of plugin logs, including the loaded plugins' open ones"""
+        self.log_max_bytes = log_max_bytes
+        for plugin_id in self.loaded_plugins:
+            for handler in logging.getLogger(f"archetype.plugins.{plugin_id}").handlers:
+                if isinstance(handler, logging.handlers.RotatingFileHandler):
+                    handler.maxBytes = log_max_bytes
+    
+    async def _initialize_plugin(self, plugin_id: str, plugin_instance: PluginBase,
+                                 manifest: Optional[Dict[str, Any]] = None) -> bool:
+        """Initialize a plugin with its stdout/stderr captured into the plugin log"""
+        if manifest is None:
+            manifest = plugin_instance.get_manifest()
+        self._register_call_graph(plugin_id, manifest)
+        
+        logger = self._get_plugin_logger(plugin_id)
+        plugin_instance.logger = logger
+        plugin_instance.services = ScopedServiceLocator(
+            self.services, lambda name: self._check_service_access(plugin_id, name)
+        )
+        
+        with self._plugin_output(plugin_id):
+            return await plugin_instance.initialize()
+    
+    @contextlib.contextmanager
+    def _plugin_output(self, plugin_id: str):
+        """Capture stdout/stderr written by plugin code run inside this block into the plugin log"""
+        _install_plugin_output_router()
+        logger = self._get_plugin_logger(plugin_id)
+        streams = (_PluginLogStream(logger, logging.INFO), _PluginLogStream(logger, logging.ERROR))
+        
+        token = _plugin_output_streams.set(streams)
+        try:
+            yield
+        finally:
+            _plugin_output_streams.reset(token)
+            for stream in streams:
+                stream.flush()
+    
+    def _register_call_graph(self, plugin_id: str, manifest: Dict[str, Any]):
+        """Record a plugin's declared call graph and check its required services against it"""
+        declared = manifest.get("call_graph")
+        call_graph = declared or {}
+        self.plugin_call_graphs[plugin_id] = {
+            "declared": declared is not None,
+            "registered_commands": call_graph.get("registered_commands", []),
+            "calls_gpu": call_graph.get("calls_gpu", False),
+            "calls_network": call_graph.get("calls_network", False),
+            "calls_filesystem": call_graph.get("calls_filesystem", False),
+            "calls_other_plugins": call_graph.get("calls_other_plugins", [])
+        }
+        
+        # Capabilities added at runtime persist across reloads
+        if declared is not None:
+            for capability in self._read_plugin_registry().get("capabilities", {}).get(plugin_id, []):
+                self.plugin_call_graphs[plugin_id][capability] = True
+        
+        for service_name in manifest.get("required_services", []):
+            self._check_service_access(plugin_id, service_name)
+    
+    def _check_service_access(self, plugin_id: str, service_name: str):
+        """Record a violation when a plugin uses a service its call graph does not declare"""
+        node = self.plugin_call_graphs.get(plugin_id)
+        flag = SERVICE_CALL_FLAGS.get(service_name)
+        
+        # Plugins without a call_graph section are not audited
+        if not node or not node["declared"] or flag is None or node[flag]:
+            return
+        
+        violation = {"plugin_id": plugin_id, "service": service_name, "undeclared": flag, "timestamp": time.time()}
+        self.call_graph_violations.append(violation)
+        logging.warning(f"⚠️ PluginCallGraphViolation: {plugin_id} requested {service_name} without declaring {flag}")
+    
+    def upgrade_plugin_capabilities(self, plugin_id: str, capabilities: List[str]) -> Dict[str, Any]:
+        """Grant a loaded plugin additional call graph capabilities without reloading it"""
+        if plugin_id not in self.loaded_plugins:
+            raise ValueError(f"Plugin not loaded: {plugin_id}")
+        
+        unknown = [c for c in capabilities if c not in PLUGIN_CAPABILITIES]
+        if unknown:
+            raise ValueError(f"Unknown capabilities: {', '.join(unknown)}")
+        
+        node = self.plugin_call_graphs.get(plugin_id)
+        if not node or not node["declared"]:
+            raise ValueError(f"Plugin {plugin_id} declares no call_graph, so its capabilities are not restricted")
+        
+        current = {c for c in PLUGIN_CAPABILITIES if node[c]}
+        removed = current - set(capabilities)
+        if removed:
+            raise CapabilityRemovalRequiresReload(
+                f"Removing capabilities {', '.join(sorted(removed))} from {plugin_id} requires a full reload"
+            )
+        
+        added = set(capabilities) - current
+        for capability in added:
+            node[capability] = True
+        
+        registry = self._read_plugin_registry()
+        registry.setdefault("capabilities", {})[plugin_id] = sorted(set(capabilities))
+        self._write_plugin_registry(registry)
+        
+        logging.info(f"⬆️ Upgraded plugin {plugin_id} capabilities: added {sorted(added) or 'none'}")
+        return dict(node)
+    
+    def get_plugin_call_graph(self) -> Dict[str, Any]:
+        """Get the declared call graph of every loaded plugin and any recorded violations"""
+        plugins = {}
+        for plugin_id, node in self.plugin_call_graphs.items():
+            if plugin_id not in self.loaded_plugins:
+                continue
+            node = dict(node)
+            if not node["registered_commands"]:
+                node["registered_commands"] = self.loaded_plugins[plugin_id].get_neural_component_types()
+            plugins[plugin_id] = node
+        
+        return {"plugins": plugins, "violations": list(self.call_graph_violations)}
+    
+    def get_plugin_log(self, plugin_id: str, lines: int = 100) -> List[str]:
+        """Get the last lines of a plugin's log file"""
+        log_path = self.get_plugin_log_path(plugin_id)
+        if not log_path.exists():
+            raise ValueError(f"No log file for plugin: {plugin_id}")
+        
+        with open(log_path, 'r', encoding='utf-8', errors='replace') as f:
+            return [line.rstrip("\n") for line in deque(f, maxlen=lines)]
+    
+    def _start_scheduled_task(self, plugin_id: str):
+        """Start the background task for a plugin whose manifest declares a schedule"""
+        schedule = self.plugin_manifests.get(plugin_id, {}).get("schedule")
+        plugin = self.loaded_plugins.get(plugin_id)
+        
+        if not schedule or not hasattr(plugin, "execute_scheduled_task"):
+            return
+        
+        interval_ms = int(schedule.get("interval_ms", 60000))
+        entry = {
+            "interval_ms": interval_ms,
+            "next_run": time.time() + interval_ms / 1000,
+            "runs": 0,
+            "errors": 0,
+            "skipped": 0,
+            "wall_time_seconds": 0.0
+        }
+        entry["task"] = asyncio.create_task(self._run_scheduled_task(plugin_id, plugin, entry))
+        self.scheduled_tasks[plugin_id] = entry
+        logging.info(f"⏰ Scheduled task for plugin {plugin_id} every {interval_ms} ms")
+    
+    async def _run_scheduled_task(self, plugin_id: str, plugin: PluginBase, entry: Dict[str, Any]):
+        """Call a plugin's scheduled task at its configured interval"""
+        while True:
+            await asyncio.sleep(max(0.0, entry["next_run"] - time.time()))
+            
+            # Paused or disabled plugins keep their schedule but their runs are skipped
+            if plugin_id in self.paused_plugins or plugin_id not in self.enabled_plugins:
+                entry["skipped"] += 1
+                entry["next_run"] = time.time() + entry["interval_ms"] / 1000
+                continue
+            
+            # Wall time: the task awaits on the shared event loop, so thread CPU time would include other tasks
+            started_at = time.perf_counter()
+            try:
+                with self._plugin_output(plugin_id):
+                    await plugin.execute_scheduled_task()
+            except Exception as e:
+                entry["errors"] += 1
+                logging.error(f"❌ Scheduled task failed for plugin {plugin_id}: {e}")
+            finally:
+                entry["runs"] += 1
+                entry["wall_time_seconds"] += time.perf_counter() - started_at
+                entry["next_run"] = time.time() + entry["interval_ms"] / 1000
+    
+    def _stop_scheduled_task(self, plugin_id: str):
+        """Cancel a plugin's background task"""
+        entry = self.scheduled_tasks.pop(plugin_id, None)
+        if entry:
+            entry["task"].cancel()
+            logging.info(f"⏰ Stopped scheduled task for plugin {plugin_id}")
+    
+    def list_scheduled_tasks(self) -> List[Dict[str, Any]]:
+        """List active scheduled tasks with their next run time"""
+        return [
+            {
+                "plugin_id": plugin_id,
+                "interval_ms": entry["interval_ms"],
+                "next_run": entry["next_run"],
+                "runs": entry["runs"],
+                "errors": entry["errors"],
+                "skipped": entry["skipped"],
+                "wall_time_seconds": entry["wall_time_seconds"]
+            }
+            for plugin_id, entry in self.scheduled_tasks.items()
+        ]
+    
     async def load_plugin(self, plugin_id: str):
         """Load plugin by ID"""
-        # Implementation for loading external plugins
         logging.info(f"Loading plugin: {plugin_id}")
-        # Add actual plugin loading logic here
+        
+        if plugin_id in self.loaded_plugins:
+            return
+        
+        if plugin_id in self.core_plugin_modules:
+            module_path, class_name = self.core_plugin_modules[plugin_id]
+            await self._load_plugin_from_module(plugin_id, module_path, class_name)
+            if plugin_id not in self.loaded_plugins:
+                raise ValueError(f"Failed to load core plugin: {plugin_id}")
+            return
+        
+        discovered = self.discover_extension_plugins()
+        if plugin_id not in discovered:
+            raise ValueError(f"Plugin {plugin_id} not found")
+        
+        await self.load_plugin_from_file(discovered[plugin_id]["path"])
     
     # Fix the incomplete method from the search results
     async def _load_plugin_from_file_complete(self, plugin_path: Path) -> str:
@@ -355,6 +1938,7 @@ class PluginManager:
             manifest = json.load(f)
         
         plugin_id = manifest["id"]
+        self._check_extension_plugin(plugin_id, plugin_path, manifest)
         
         # Load Python module
         main_module = manifest.get("main_module", "plugin.py")
@@ -367,16 +1951,21 @@ class PluginManager:
         spec = importlib.util.spec_from_file_location(plugin_id, module_path)
         if spec is not None and spec.loader is not None:
             module = importlib.util.module_from_spec(spec)
-            spec.loader.exec_module(module)
+            with self._plugin_output(plugin_id):
+                spec.loader.exec_module(module)
             
             # Get plugin class (assuming it's named 'Plugin')
             plugin_class = getattr(module, 'Plugin', None)
             if plugin_class:
-                plugin_instance = plugin_class()
-                if await plugin_instance.initialize():
+                with self._plugin_output(plugin_id):
+                    plugin_instance = plugin_class()
+                if await self._initialize_plugin(plugin_id, plugin_instance, manifest):
                     self.loaded_plugins[plugin_id] = plugin_instance
                     self.plugin_manifests[plugin_id] = manifest
+                    self.plugin_paths[plugin_id] = plugin_path
                     self.enabled_plugins.add(plugin_id)
+                    self._record_plugin_hash(plugin_id)
+                    self._start_scheduled_task(plugin_id)
                     logging.info(f"✅ Loaded plugin: {plugin_id}")
                     return plugin_id
                 else:
@@ -0,0 +1,42 @@
+import logging
+from typing import Callable, Dict, Any, List, Optional
+
+
+class ServiceLocator:
+    """Named backend services that plugins can request instead of reaching into app state"""
+    
+    def __init__(self):
+        self._services: Dict[str, Any] = {}
+    
+    def register_service(self, name: str, service: Any):
+        """Register a service under a name, replacing any previous registration"""
+        self._services[name] = service
+        logging.info(f"🧩 Registered service: {name}")
+    
+    def request_service(self, name: str) -> Optional[Any]:
+        """Get a service by name, or None if it is not registered"""
+        service = self._services.get(name)
+        if service is None:
+            logging.warning(f"⚠️ Requested unknown service: {name}")
+        return service
+    
+    def list_services(self) -> List[str]:
+        """Get the names of all registered services"""
+        return list(self._services.keys())
+
+
+class ScopedServiceLocator:
+    """A plugin's view of the service locator that reports each service it requests"""
+    
+    def __init__(self, locator: ServiceLocator, on_request: Callable[[str], None]):
+        self._locator = locator
+        self._on_request = on_request
+    
+    def request_service(self, name: str) -> Optional[Any]:
+        """Get a service by name, or None if it is not registered"""
+        self._on_request(name)
+        return self._locator.request_service(name)
+    
+    def list_services(self) -> List[str]:
+        """Get the names of all registered services"""
+        return self._locator.list_services()
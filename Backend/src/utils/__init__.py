@@ -1,9 +1,11 @@
 from .config import Settings
 from .logging_config import setup_logging
 from .websocket_manager import WebSocketManager
+from .health_state import HealthState
 
 __all__ = [
     "Settings",
     "setup_logging", 
-    "WebSocketManager"
+    "WebSocketManager",
+    "HealthState"
 ]
\ No newline at end of file
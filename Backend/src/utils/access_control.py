@@ -0,0 +1,106 @@
+import fnmatch
+import hashlib
+import json
+import logging
+import os
+import secrets
+from pathlib import Path
+from typing import Any, Dict, List, Optional, Tuple
+
+# Routes that stay open so probes, scrapers and API docs work without a key
+PUBLIC_PATHS = ["/", "/api/v1/health*", "/metrics", "/docs*", "/redoc*", "/openapi.json"]
+
+
+def hash_api_key(api_key: str) -> str:
+    """Hash an API key for storage"""
+    return hashlib.sha256(api_key.encode("utf-8")).hexdigest()
+
+
+class AccessControl:
+    """Role-based access to API routes, keyed by X-API-Key"""
+    
+    def __init__(self, path: Path = Path("access_control.json"), admin_api_key: Optional[str] = None):
+        self.path = Path(path)
+        self.admin_api_key = admin_api_key
+        self.users: List[Dict[str, Any]] = []
+        self.permissions: Dict[str, List[str]] = {}  # role -> ["METHOD /path-glob", ...]
+        self.reload()
+    
+    @property
+    def enabled(self) -> bool:
+        """Access control applies once at least one user is configured"""
+        return bool(self.users)
+    
+    def reload(self):
+        """Re-read users and role permissions from disk"""
+        if not self.path.exists():
+            self.users, self.permissions = [], {}
+            return
+        
+        try:
+            with open(self.path, 'r') as f:
+                data = json.load(f)
+            self.users = data.get("users", [])
+            self.permissions = data.get("permissions", {})
+            logging.info(f"🔐 Loaded access control: {len(self.users)} users, {len(self.permissions)} roles")
+        except Exception as e:
+            logging.error(f"❌ Failed to load access control from {self.path}: {e}")
+    
+    def resolve_roles(self, api_key: str) -> Optional[List[str]]:
+        """Get the roles for an API key, or None if the key is unknown"""
+        if not api_key:
+            return None
+        if self.admin_api_key and secrets.compare_digest(api_key, self.admin_api_key):
+            return ["admin"]
+        
+        key_hash = hash_api_key(api_key)
+        for user in self.users:
+            if secrets.compare_digest(user.get("api_key_hash", ""), key_hash):
+                return user.get("roles", [])
+        return None
+    
+    def authorize(self, api_key: str, method: str, path: str) -> Tuple[int, Optional[Dict[str, Any]]]:
+        """Check a request; returns (200, None) if allowed, else (status, error body)"""
+        if any(fnmatch.fnmatchcase(path, pattern) for pattern in PUBLIC_PATHS):
+            return 200, None
+        
+        roles = self.resolve_roles(api_key)
+        if roles is None:
+            return 401, {"error": "Unauthorized", "message": "Invalid or missing X-API-Key"}
+        
+        command = f"{method} {path}"
+        if "admin" in roles or any(self._role_allows(role, command) for role in roles):
+            return 200, None
+        
+        required_roles = sorted(["admin"] + [role for role in self.permissions if self._role_allows(role, command)])
+        return 403, {"error": "Forbidden", "command": command, "required_roles": required_roles}
+    
+    def _role_allows(self, role: str, command: str) -> bool:
+        return any(fnmatch.fnmatchcase(command, pattern) for pattern in self.permissions.get(role, []))
+    
+    def add_user(self, name: str, api_key: str, roles: List[str]):
+        """Add or replace a user, storing only the API key hash"""
+        self.users = [u for u in self.users if u.get("name") != name]
+        self.users.append({"name": name, "api_key_hash": hash_api_key(api_key), "roles": roles})
+        self._save()
+        logging.getLogger("archetype.audit").warning(f"User {name} added with roles {roles}")
+    
+    def revoke_user(self, name: str):
+        """Remove a user"""
+        remaining = [u for u in self.users if u.get("name") != name]
+        if len(remaining) == len(self.users):
+            raise ValueError(f"User not found: {name}")
+        self.users = remaining
+        self._save()
+        logging.getLogger("archetype.audit").warning(f"User {name} revoked")
+    
+    def list_users(self) -> List[Dict[str, Any]]:
+        """List users without their key hashes"""
+        return [{"name": u.get("name"), "roles": u.get("roles", [])} for u in self.users]
+    
+    def _save(self):
+        # Write to a temporary file first so a crash never leaves a half-written file
+        tmp_path = self.path.with_suffix(self.path.suffix + ".tmp")
+        with open(tmp_path, 'w') as f:
+            json.dump({"users": self.users, "permissions": self.permissions}, f, indent=2)
+        os.replace(tmp_path, self.path)
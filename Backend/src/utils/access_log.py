@@ -0,0 +1,104 @@
+import asyncio
+import logging
+import queue
+import threading
+import time
+from pathlib import Path
+from typing import Callable, List, Optional
+
+from .asgi_capture import ResponseCapture, scope_path_with_query
+
+# Queued after the last line to stop the writer thread
+_STOP = object()
+
+
+class AccessLogger:
+    """Writes HTTP access lines in Common Log Format to a dedicated file from a writer thread, off the request path"""
+    
+    def __init__(self, path: Path, flush_interval_seconds: float = 0.1, flush_lines: int = 1000):
+        self.path = Path(path)
+        self.flush_interval_seconds = flush_interval_seconds
+        self.flush_lines = flush_lines
+        self.queue: queue.Queue = queue.Queue()
+        self._thread: Optional[threading.Thread] = None
+    
+    def start(self):
+        """Start the background writer"""
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        self._thread = threading.Thread(target=self._writer, name=f"writer-{self.path.name}", daemon=True)
+        self._thread.start()
+        logging.info(f"📝 Access log enabled: {self.path}")
+    
+    async def stop(self):
+        """Stop the writer after flushing queued lines"""
+        if self._thread:
+            self.queue.put(_STOP)
+            await asyncio.to_thread(self._thread.join)
+            self._thread = None
+    
+    def log(self, remote_addr: str, method: str, path: str, status: int, bytes_sent: Optional[int],
+            http_version: str = "1.1"):
+        """Queue one request line; never blocks the caller"""
+        timestamp = time.strftime("%d/%b/%Y:%H:%M:%S %z")
+        size = str(bytes_sent) if bytes_sent is not None else "-"
+        self.queue.put_nowait(f'{remote_addr} - - [{timestamp}] "{method} {path} HTTP/{http_version}" {status} {size}')
+    
+    def _write(self, lines: List[str]):
+        try:
+            with open(self.path, 'a', encoding='utf-8') as f:
+                f.write("\n".join(lines) + "\n")
+        except OSError as e:
+            logging.error(f"❌ Failed to write {len(lines)} lines to {self.path}: {e}")
+    
+    def _writer(self):
+        # Block until a line arrives, then batch what follows within flush_interval_seconds, up to flush_lines
+        while True:
+            line = self.queue.get()
+            if line is _STOP:
+                return
+            
+            lines = [line]
+            deadline = time.monotonic() + self.flush_interval_seconds
+            while len(lines) < self.flush_lines:
+                try:
+                    line = self.queue.get(timeout=max(0.0, deadline - time.monotonic()))
+                except queue.Empty:
+                    break
+                if line is _STOP:
+                    self._write(lines)
+                    return
+                lines.append(line)
+            self._write(lines)
+
+
+class AccessLogMiddleware:
+    """Pure ASGI middleware writing one access line per request, declared outermost so it sees every response.
+    
+    The line is written after the response finishes (or fails), with the bytes actually sent, so
+    streamed responses without a content-length and rejections from other middleware are counted.
+    """
+    
+    def __init__(self, app, logger_provider: Callable[[], Optional[AccessLogger]]):
+        self.app = app
+        self.logger_provider = logger_provider  # None until the access log is enabled at startup
+    
+    async def __call__(self, scope, receive, send):
+        if scope["type"] != "http" or self.logger_provider() is None:
+            await self.app(scope, receive, send)
+            return
+        
+        response = ResponseCapture()
+        try:
+            await self.app(scope, receive, response.wrap(send))
+        finally:
+            access_logger = self.logger_provider()
+            if access_logger is not None:
+                client = scope.get("client")
+                access_logger.log(
+                    client[0] if client else "-",
+                    scope["method"],
+                    scope_path_with_query(scope),
+                    response.status or 500,
+                    response.bytes_sent if response.status is not None else None,
+                    scope.get("http_version", "1.1")
+                )
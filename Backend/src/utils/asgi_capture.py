@@ -0,0 +1,94 @@
+import json
+from typing import Any, Awaitable, Callable, Dict, List, Optional
+
+Receive = Callable[[], Awaitable[Dict[str, Any]]]
+Send = Callable[[Dict[str, Any]], Awaitable[None]]
+
+# JSON fields whose values are never stored or logged, e.g. the api_key in POST /api/v1/users
+SECRET_FIELDS = {"api_key", "admin_api_key", "password", "secret", "token"}
+REDACTED = "[REDACTED]"
+
+
+class BodyCapture:
+    """Copy of a streamed body, keeping at most max_bytes (None keeps everything)"""
+
+    def __init__(self, max_bytes: Optional[int] = None):
+        self.max_bytes = max_bytes
+        self.chunks: List[bytes] = []
+        self.kept_bytes = 0
+        self.total_bytes = 0
+        self.truncated = False
+
+    def add(self, chunk: bytes):
+        self.total_bytes += len(chunk)
+        if self.max_bytes is not None and self.kept_bytes + len(chunk) > self.max_bytes:
+            chunk = chunk[:self.max_bytes - self.kept_bytes]
+            self.truncated = True
+        if chunk:
+            self.chunks.append(chunk)
+            self.kept_bytes += len(chunk)
+
+    @property
+    def body(self) -> bytes:
+        return b"".join(self.chunks)
+
+
+def tee_receive(receive: Receive, capture: BodyCapture) -> Receive:
+    """Wrap an ASGI receive so request body chunks are copied as the app reads them"""
+    async def receive_and_copy() -> Dict[str, Any]:
+        message = await receive()
+        if message["type"] == "http.request":
+            capture.add(message.get("body", b""))
+        return message
+    return receive_and_copy
+
+
+class ResponseCapture:
+    """Status and body bytes of a response, observed as it is sent; the body is copied only when requested"""
+
+    def __init__(self, body: Optional[BodyCapture] = None):
+        self.status: Optional[int] = None
+        self.bytes_sent = 0
+        self.body = body
+
+    def wrap(self, send: Send) -> Send:
+        async def send_and_observe(message: Dict[str, Any]):
+            if message["type"] == "http.response.start":
+                self.status = message["status"]
+            elif message["type"] == "http.response.body":
+                chunk = message.get("body", b"")
+                self.bytes_sent += len(chunk)
+                if self.body is not None:
+                    self.body.add(chunk)
+            await send(message)
+        return send_and_observe
+
+
+def scope_route_path(scope: Dict[str, Any]) -> str:
+    """The matched route template (e.g. /api/v1/plugins/{plugin_id}), or the raw path when no route matched"""
+    route = scope.get("route")
+    return route.path if route is not None else scope["path"]
+
+
+def scope_path_with_query(scope: Dict[str, Any]) -> str:
+    query = scope.get("query_string", b"").decode("latin-1")
+    return scope["path"] + (f"?{query}" if query else "")
+
+
+def redact_secrets(body: bytes) -> bytes:
+    """Mask secret fields in a JSON body; a body that can't be parsed but names a secret field is dropped entirely"""
+    try:
+        data = json.loads(body)
+    except ValueError:
+        lowered = body.lower()
+        return REDACTED.encode() if any(field.encode() in lowered for field in SECRET_FIELDS) else body
+
+    def redact(value: Any) -> Any:
+        if isinstance(value, dict):
+            return {k: REDACTED if k.lower() in SECRET_FIELDS else redact(v) for k, v in value.items()}
+        if isinstance(value, list):
+            return [redact(item) for item in value]
+        return value
+
+    redacted = redact(data)
+    return body if redacted == data else json.dumps(redacted).encode()
@@ -0,0 +1,61 @@
+import logging
+import time
+from enum import Enum
+
+
+class CircuitState(Enum):
+    CLOSED = "closed"
+    OPEN = "open"
+    HALF_OPEN = "half_open"
+
+
+class CircuitBreaker:
+    """Fast-fails requests to a subsystem after repeated failures, probing again after a cool-down"""
+    
+    def __init__(self, name: str, failure_threshold: int = 5, cooldown_seconds: float = 30.0):
+        self.name = name
+        self.failure_threshold = failure_threshold
+        self.cooldown_seconds = cooldown_seconds
+        self.state = CircuitState.CLOSED
+        self.consecutive_failures = 0
+        self.opened_at = 0.0
+        self._probe_in_flight = False
+    
+    def allow_request(self) -> bool:
+        """Check whether a request may reach the subsystem"""
+        if self.state == CircuitState.OPEN:
+            if time.monotonic() - self.opened_at < self.cooldown_seconds:
+                return False
+            self.state = CircuitState.HALF_OPEN
+            self._probe_in_flight = False
+            logging.info(f"🔌 Circuit {self.name} half-open, allowing a probe request")
+        
+        if self.state == CircuitState.HALF_OPEN:
+            if self._probe_in_flight:
+                return False
+            self._probe_in_flight = True
+        
+        return True
+    
+    def record_success(self):
+        """Record a successful request"""
+        if self.state != CircuitState.CLOSED:
+            logging.info(f"✅ Circuit {self.name} closed")
+        self.state = CircuitState.CLOSED
+        self.consecutive_failures = 0
+        self._probe_in_flight = False
+    
+    def record_failure(self):
+        """Record a failed request, opening the circuit once the threshold is reached"""
+        self.consecutive_failures += 1
+        self._probe_in_flight = False
+        
+        if self.state == CircuitState.HALF_OPEN or self.consecutive_failures >= self.failure_threshold:
+            if self.state != CircuitState.OPEN:
+                logging.warning(f"⚠️ Circuit {self.name} opened after {self.consecutive_failures} consecutive failures")
+            self.state = CircuitState.OPEN
+            self.opened_at = time.monotonic()
+    
+    def reset(self):
+        """Force the circuit closed"""
+        self.record_success()
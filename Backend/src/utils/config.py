@@ -1,26 +1,160 @@
-from pydantic_settings import BaseSettings
+import json
+import logging
+
+from pydantic import AliasChoices, Field
+from pydantic_settings import BaseSettings, DotEnvSettingsSource, EnvSettingsSource, PydanticBaseSettingsSource
+
+from .request_logging import LogVerbosity
 from pathlib import Path
-from typing import Optional
+from typing import Any, Dict, List, Optional, Tuple, Type
+
+ENV_PREFIX = "ARCHETYPE_"
+
+# Fields never printed by --print-config or handed to plugins through the config_store service
+SECRET_SETTINGS = ("admin_api_key",)
+
+
+def _legacy_values(settings_cls: Type[BaseSettings], values: Dict[str, Any], origin: str) -> Dict[str, Any]:
+    """Keep the unprefixed values that name a field, warning once for each"""
+    fields = {name for name, info in settings_cls.model_fields.items() if info.validation_alias is None}
+    legacy = {key: value for key, value in values.items() if key in fields}
+    for key in legacy:
+        logging.warning(f"⚠️ Unprefixed {origin} {key.upper()} is deprecated; use {ENV_PREFIX}{key.upper()}")
+    return legacy
+
+
+class _LegacyEnvSettingsSource(EnvSettingsSource):
+    """Environment variables without the ARCHETYPE_ prefix, as read before the prefix was introduced"""
+    
+    def __init__(self, settings_cls: Type[BaseSettings]):
+        super().__init__(settings_cls, env_prefix="")
+    
+    def __call__(self) -> Dict[str, Any]:
+        return _legacy_values(self.settings_cls, super().__call__(), "environment variable")
+
+
+class _LegacyDotEnvSettingsSource(DotEnvSettingsSource):
+    """.env keys without the ARCHETYPE_ prefix"""
+    
+    def __init__(self, settings_cls: Type[BaseSettings], dotenv_settings: PydanticBaseSettingsSource):
+        super().__init__(settings_cls, env_file=getattr(dotenv_settings, "env_file", None),
+                         env_file_encoding=getattr(dotenv_settings, "env_file_encoding", None), env_prefix="")
+    
+    def __call__(self) -> Dict[str, Any]:
+        return _legacy_values(self.settings_cls, super().__call__(), ".env key")
+
 
 class Settings(BaseSettings):
     # Existing settings...
     host: str = "localhost"
     port: int = 8000
+    log_level: str = "info"
+    # Deployment environment (development, staging, production); also read from ARCHETYPE_ENV
+    environment: str = Field("development", validation_alias=AliasChoices("ARCHETYPE_ENV", "ARCHETYPE_ENVIRONMENT"))
+    access_log_path: Optional[Path] = None  # Common Log Format access log; disabled when unset
+    # Request logging per path (fnmatch patterns, first match wins): none, minimal, standard, verbose
+    default_log_verbosity: LogVerbosity = LogVerbosity.MINIMAL
+    log_verbosity_overrides: Dict[str, LogVerbosity] = {
+        "/docs*": LogVerbosity.VERBOSE,
+        "/api/v1/diagnostics": LogVerbosity.VERBOSE,
+        "/metrics": LogVerbosity.NONE,
+        "/api/v1/health*": LogVerbosity.NONE,
+    }
+    record_traffic: bool = False  # Capture requests and responses to traffic_log_path for --replay
+    traffic_log_path: Path = Path("traffic.jsonl")
+    traffic_record_max_body_bytes: int = 1024 * 1024
     plugin_directory: Path = Path("src/plugins")
+    profile_directory: Path = Path("profiles")
+    export_directory: Path = Path("exports")
+    diagnostics_directory: Path = Path("diagnostics")  # run_diagnostics writes diagnostics_<timestamp>.json here
+    plugin_log_max_bytes: int = 10 * 1024 * 1024
+    run_plugin_self_tests: bool = False
+    plugin_version_history_count: int = 3
+    max_plugin_call_depth: int = 16
+    plugin_rollback_window_ms: int = 30000  # A reloaded plugin that turns unhealthy within this window is rolled back
+    max_startup_duration_ms: int = 5000  # Per-subsystem; slower subsystems log a warning
     
     # GPU Configuration
     gpu_preference: str = "auto"  # auto, gpu_only, cpu_only, nvidia_only, amd_only, intel_only
     preferred_device_id: Optional[str] = None
     enable_gpu_monitoring: bool = True
     benchmark_on_startup: bool = False
+    lazy_gpu_init: bool = True  # Defer device detection until first GPU use; --headless turns this off
+    gpu_mock_file: Optional[Path] = None  # ARCHETYPE_GPU_MOCK_FILE: report devices from JSON instead of hardware
+    allow_gpu_mock: bool = False  # must be enabled explicitly; keep off in production
     gpu_memory_fraction: float = 0.8  # Use 80% of GPU memory max
     enable_mixed_precision: bool = True
     
+    # Wrap /api/v1 JSON responses in {api_version, command, request_id, timestamp_ms, duration_ms, data}
+    enable_response_envelope: bool = False
+    
+    # Rate limiting (per client address)
+    rate_limit_requests: int = 600
+    rate_limit_window_seconds: int = 60
+    
+    # Responses to POSTs with an X-Idempotency-Key are replayed for this long
+    idempotency_ttl_seconds: int = 300
+    idempotency_wait_timeout_seconds: float = 30.0  # retries arriving while the first request runs wait this long, then get 409
+    
+    # Feature flags persist here; changing them requires admin_api_key in X-API-Key
+    feature_flags_path: Path = Path("feature_flags.json")
+    admin_api_key: Optional[str] = None
+    access_control_path: Path = Path("access_control.json")  # Users and role permissions
+    
+    # Path patterns (fnmatch); when the allowlist is set only matching routes are served, denylist always wins
+    route_allowlist: Optional[List[str]] = None
+    route_denylist: List[str] = []
+    
+    # Content-Security-Policy sent with every response; omitted when unset
+    csp_header: Optional[str] = None
+    
+    # Circuit breakers per subsystem (gpu, plugins, compute)
+    circuit_failure_threshold: int = 5
+    circuit_cooldown_seconds: float = 30.0
+    
+    # Graceful shutdown waits this long for in-flight requests
+    drain_timeout_ms: int = 10000
+    
     # Performance settings
     cpu_threads: Optional[int] = None  # Auto-detect if None
     enable_cpu_optimizations: bool = True
     memory_pool_size_mb: int = 1024
     
     class Config:
+        # Every field can be overridden by an ARCHETYPE_<FIELD> environment variable,
+        # which takes priority over the .env file
+        env_prefix = ENV_PREFIX
         env_file = ".env"
-        env_file_encoding = "utf-8"
\ No newline at end of file
+        env_file_encoding = "utf-8"
+        extra = "ignore"  # unprefixed .env keys are read by the legacy source instead of rejected
+    
+    @classmethod
+    def settings_customise_sources(
+        cls,
+        settings_cls: Type[BaseSettings],
+        init_settings: PydanticBaseSettingsSource,
+        env_settings: PydanticBaseSettingsSource,
+        dotenv_settings: PydanticBaseSettingsSource,
+        file_secret_settings: PydanticBaseSettingsSource,
+    ) -> Tuple[PydanticBaseSettingsSource, ...]:
+        # Unprefixed names from older deployments still apply, below any ARCHETYPE_ value
+        return (
+            init_settings,
+            env_settings,
+            dotenv_settings,
+            _LegacyEnvSettingsSource(settings_cls),
+            _LegacyDotEnvSettingsSource(settings_cls, dotenv_settings),
+            file_secret_settings,
+        )
+    
+    def without_secrets(self) -> "Settings":
+        """Copy with secret fields cleared, for code that must not see credentials"""
+        return self.model_copy(update={name: None for name in SECRET_SETTINGS})
+    
+    def redacted_json(self) -> str:
+        """JSON dump with secret values masked; unset secrets stay null so they read as unset"""
+        data = self.model_dump(mode="json")
+        for name in SECRET_SETTINGS:
+            if data.get(name) is not None:
+                data[name] = "**********"
+        return json.dumps(data, indent=2)
@@ -0,0 +1,67 @@
+import logging
+from typing import Dict, Any
+
+from .config import Settings
+
+# Settings that are only read at startup, so they take effect when the server is restarted
+RESTART_REQUIRED_FIELDS = [
+    "host", "port", "plugin_directory", "profile_directory", "environment",
+    "access_log_path", "record_traffic", "traffic_log_path", "feature_flags_path", "access_control_path",
+    "lazy_gpu_init", "gpu_mock_file", "allow_gpu_mock",
+    "run_plugin_self_tests", "max_startup_duration_ms",
+    "drain_timeout_ms",  # also uvicorn's graceful shutdown timeout, fixed when the server starts
+]
+
+
+def reload_settings(state) -> Dict[str, Any]:
+    """Reload settings from the environment/.env and apply the non-disruptive changes"""
+    old_settings: Settings = state.settings
+    new_settings = Settings()
+    
+    changed = [
+        name for name in Settings.model_fields
+        if getattr(old_settings, name) != getattr(new_settings, name)
+    ]
+    restart_required = [name for name in changed if name in RESTART_REQUIRED_FIELDS]
+    applied = [name for name in changed if name not in RESTART_REQUIRED_FIELDS]
+    
+    for name in restart_required:
+        logging.warning(f"⚠️ Config change to '{name}' requires a restart to take effect")
+        # Keep the running value so app state reflects what is actually in use
+        setattr(new_settings, name, getattr(old_settings, name))
+    
+    if "log_level" in applied:
+        logging.getLogger().setLevel(getattr(logging, new_settings.log_level.upper()))
+    
+    rate_limiter = getattr(state, 'rate_limiter', None)
+    if rate_limiter:
+        rate_limiter.max_requests = new_settings.rate_limit_requests
+        rate_limiter.window_seconds = new_settings.rate_limit_window_seconds
+    
+    access_control = getattr(state, 'access_control', None)
+    if access_control:
+        access_control.admin_api_key = new_settings.admin_api_key
+        access_control.reload()
+    
+    for breaker in getattr(state, 'circuit_breakers', {}).values():
+        breaker.failure_threshold = new_settings.circuit_failure_threshold
+        breaker.cooldown_seconds = new_settings.circuit_cooldown_seconds
+    
+    idempotency_cache = getattr(state, 'idempotency_cache', None)
+    if idempotency_cache:
+        idempotency_cache.ttl_seconds = new_settings.idempotency_ttl_seconds
+    
+    plugin_manager = getattr(state, 'plugin_manager', None)
+    if plugin_manager:
+        plugin_manager.set_log_max_bytes(new_settings.plugin_log_max_bytes)
+        plugin_manager.version_history_count = new_settings.plugin_version_history_count
+        plugin_manager.max_call_depth = new_settings.max_plugin_call_depth
+        plugin_manager.rollback_window_ms = new_settings.plugin_rollback_window_ms
+        plugin_manager.services.register_service("config_store", new_settings.without_secrets())
+        discovered = plugin_manager.discover_extension_plugins()
+        logging.info(f"🔍 Plugin directory rescan found {len(discovered)} extension plugins")
+    
+    state.settings = new_settings
+    logging.info(f"🔄 Configuration reloaded - applied: {applied or 'none'}")
+    
+    return {"applied": applied, "restart_required": restart_required}
@@ -0,0 +1,131 @@
+import asyncio
+import json
+import logging
+import time
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+import psutil
+import torch
+
+from src.utils.circuit_breaker import CircuitState
+from src.utils.health_state import HealthState
+
+
+@dataclass
+class DiagnosticCheck:
+    """Outcome of one diagnostic check"""
+    name: str
+    status: str  # pass, warn, fail, skipped
+    message: str
+    duration_ms: float = 0.0
+    details: Dict[str, Any] = field(default_factory=dict)
+
+
+@dataclass
+class DiagnosticsReport:
+    """Every diagnostic check plus an overall verdict, suitable for attaching to a bug report"""
+    overall_health: str
+    timestamp: str
+    checks: List[DiagnosticCheck] = field(default_factory=list)
+
+
+async def _gpu_benchmark_check(gpu_manager) -> DiagnosticCheck:
+    results = await gpu_manager.run_benchmark(matrix_sizes=[1024])
+    if "error" in results:
+        return DiagnosticCheck("gpu_benchmark", "fail", results["error"])
+    return DiagnosticCheck("gpu_benchmark", "pass", f"{results['overall_gflops']:.2f} GFLOPS", details=results)
+
+
+async def _compute_validation_check(gpu_manager) -> DiagnosticCheck:
+    device = gpu_manager.get_device()
+    result = (torch.ones(4, device=device) + torch.ones(4, device=device)).sum().item()
+    if result != 8.0:
+        return DiagnosticCheck("compute_validation", "fail", f"Expected 8.0 on {device}, got {result}")
+    return DiagnosticCheck("compute_validation", "pass", f"Tensor arithmetic correct on {device}")
+
+
+async def _plugin_self_test_check(plugin_manager) -> DiagnosticCheck:
+    reports = await plugin_manager.run_all_self_tests()
+    failed = [r.plugin_id for r in reports if not r.passed and not r.skipped]
+    details = {"reports": [asdict(r) for r in reports]}
+    if failed:
+        return DiagnosticCheck("plugin_self_tests", "fail", f"Failed: {', '.join(failed)}", details=details)
+    return DiagnosticCheck("plugin_self_tests", "pass", f"{len(reports)} plugins tested", details=details)
+
+
+async def _memory_budget_check(gpu_manager) -> DiagnosticCheck:
+    memory = psutil.virtual_memory()
+    details = {"system_memory_percent": memory.percent, "system_available_mb": memory.available // (1024 * 1024)}
+    if gpu_manager is not None and gpu_manager.selected_device:
+        details["device_memory_percent"] = gpu_manager.selected_device.memory_usage_percent
+    
+    worst = max(details["system_memory_percent"], details.get("device_memory_percent", 0.0))
+    if worst > 90:
+        return DiagnosticCheck("memory_budget", "warn", f"Memory usage at {worst:.0f}%", details=details)
+    return DiagnosticCheck("memory_budget", "pass", f"Memory usage at {worst:.0f}%", details=details)
+
+
+async def _event_loop_lag_check() -> DiagnosticCheck:
+    # The backend runs on one event loop, so blocked callbacks show up as sleep overshoot
+    started_at = time.perf_counter()
+    await asyncio.sleep(0.01)
+    lag_ms = (time.perf_counter() - started_at) * 1000 - 10
+    status = "warn" if lag_ms > 100 else "pass"
+    return DiagnosticCheck("event_loop_lag", status, f"Event loop lag {lag_ms:.1f} ms", details={"lag_ms": lag_ms})
+
+
+async def _circuit_breaker_check(circuit_breakers: Dict[str, Any]) -> DiagnosticCheck:
+    states = {name: breaker.state.value for name, breaker in circuit_breakers.items()}
+    tripped = [name for name, breaker in circuit_breakers.items() if breaker.state != CircuitState.CLOSED]
+    if tripped:
+        return DiagnosticCheck("circuit_breakers", "fail", f"Not closed: {', '.join(tripped)}", details=states)
+    return DiagnosticCheck("circuit_breakers", "pass", "All circuits closed", details=states)
+
+
+async def run_diagnostics(gpu_manager=None, plugin_manager=None,
+                          circuit_breakers: Optional[Dict[str, Any]] = None) -> DiagnosticsReport:
+    """Run the diagnostic checks in sequence; checks whose subsystem is not given are skipped"""
+    checks: List[tuple] = [
+        ("gpu_benchmark", gpu_manager, lambda: _gpu_benchmark_check(gpu_manager)),
+        ("compute_validation", gpu_manager, lambda: _compute_validation_check(gpu_manager)),
+        ("plugin_self_tests", plugin_manager, lambda: _plugin_self_test_check(plugin_manager)),
+        ("memory_budget", True, lambda: _memory_budget_check(gpu_manager)),
+        ("event_loop_lag", True, _event_loop_lag_check),
+        ("circuit_breakers", circuit_breakers, lambda: _circuit_breaker_check(circuit_breakers)),
+    ]
+    
+    results = []
+    for name, subsystem, run_check in checks:
+        if subsystem is None:
+            results.append(DiagnosticCheck(name, "skipped", "Subsystem not initialized"))
+            continue
+        
+        started_at = time.perf_counter()
+        try:
+            result = await run_check()
+        except Exception as e:
+            result = DiagnosticCheck(name, "fail", f"Check raised: {e}")
+        result.duration_ms = (time.perf_counter() - started_at) * 1000
+        results.append(result)
+    
+    overall = HealthState.DEGRADED if any(r.status in ("warn", "fail") for r in results) else HealthState.READY
+    report = DiagnosticsReport(
+        overall_health=overall.value,
+        timestamp=time.strftime("%Y-%m-%dT%H:%M:%S"),
+        checks=results
+    )
+    logging.info(f"🩺 Diagnostics complete - {overall.value}")
+    return report
+
+
+def write_diagnostics_report(report: DiagnosticsReport, directory: Path) -> Path:
+    """Write the report to diagnostics_<timestamp>.json in directory"""
+    directory = Path(directory)
+    directory.mkdir(parents=True, exist_ok=True)
+    
+    report_path = directory / f"diagnostics_{time.strftime('%Y%m%d_%H%M%S')}.json"
+    with open(report_path, 'w') as f:
+        json.dump(asdict(report), f, indent=2, default=str)
+    return report_path
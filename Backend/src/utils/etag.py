@@ -0,0 +1,20 @@
+import hashlib
+import json
+from typing import Any
+
+from fastapi import Request, Response
+from fastapi.encoders import jsonable_encoder
+from fastapi.responses import JSONResponse
+
+
+def etag_response(request: Request, content: Any) -> Response:
+    """Return content as JSON with an ETag, or 304 Not Modified if the client already has it"""
+    content = jsonable_encoder(content)
+    body = json.dumps(content, sort_keys=True, separators=(",", ":"))
+    etag = f'"{hashlib.sha256(body.encode("utf-8")).hexdigest()}"'
+    
+    if_none_match = request.headers.get("if-none-match", "")
+    if etag in [tag.strip() for tag in if_none_match.split(",")] or if_none_match.strip() == "*":
+        return Response(status_code=304, headers={"ETag": etag})
+    
+    return JSONResponse(content=content, headers={"ETag": etag})
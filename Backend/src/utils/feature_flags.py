@@ -0,0 +1,72 @@
+import json
+import logging
+import os
+from pathlib import Path
+from typing import Dict, Mapping, Optional
+
+audit_logger = logging.getLogger("archetype.audit")
+
+# Feature -> API route prefixes it gates
+FEATURE_ROUTES: Dict[str, tuple] = {
+    "gpu": ("/api/v1/gpu",),
+    "plugins": ("/api/v1/plugins",),
+    "compute": ("/api/v1/models", "/api/v1/training"),
+    "http_uploads": (),  # Not tied to routes: gates every request that uploads a body, see disabled_feature_for_request
+}
+
+UPLOAD_METHODS = ("POST", "PUT", "PATCH")
+
+
+class FeatureFlags:
+    """Runtime switches for whole API areas, persisted across restarts"""
+    
+    def __init__(self, path: Path = Path("feature_flags.json")):
+        self.path = Path(path)
+        self.flags: Dict[str, bool] = {feature: True for feature in FEATURE_ROUTES}
+        
+        if self.path.exists():
+            try:
+                with open(self.path, 'r') as f:
+                    saved = json.load(f)
+                self.flags.update({k: bool(v) for k, v in saved.items() if k in FEATURE_ROUTES})
+            except Exception as e:
+                logging.warning(f"⚠️ Could not read feature flags from {self.path}: {e}")
+    
+    def is_enabled(self, feature: str) -> bool:
+        """Check whether a feature is enabled"""
+        return self.flags.get(feature, True)
+    
+    def set_flag(self, feature: str, enabled: bool, changed_by: str = "unknown"):
+        """Enable or disable a feature, persist it and write an audit entry"""
+        if feature not in FEATURE_ROUTES:
+            raise ValueError(f"Unknown feature: {feature}. Available: {list(FEATURE_ROUTES)}")
+        
+        previous = self.flags[feature]
+        self.flags[feature] = enabled
+        
+        # Write to a temporary file first so a crash never leaves a half-written file
+        tmp_path = self.path.with_suffix(self.path.suffix + ".tmp")
+        with open(tmp_path, 'w') as f:
+            json.dump(self.flags, f, indent=2)
+        os.replace(tmp_path, self.path)
+        
+        audit_logger.warning(f"Feature flag {feature} changed {previous} -> {enabled} by {changed_by}")
+    
+    def disabled_feature_for_request(self, method: str, path: str, headers: Mapping[str, str]) -> Optional[str]:
+        """Get the disabled feature blocking a request, if any; uploads are checked before the route's own feature"""
+        content_length = headers.get("content-length", "0")
+        uploads_body = method in UPLOAD_METHODS and (
+            "transfer-encoding" in headers or (content_length.isdigit() and int(content_length) > 0)
+        )
+        if uploads_body and not self.is_enabled("http_uploads"):
+            return "http_uploads"
+        
+        feature = self.feature_for_path(path)
+        return feature if feature and not self.is_enabled(feature) else None
+    
+    def feature_for_path(self, path: str) -> Optional[str]:
+        """Get the feature gating a request path, if any"""
+        for feature, prefixes in FEATURE_ROUTES.items():
+            if any(path == prefix or path.startswith(prefix + "/") for prefix in prefixes):
+                return feature
+        return None
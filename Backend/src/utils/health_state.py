@@ -0,0 +1,10 @@
+from enum import Enum
+
+
+class HealthState(Enum):
+    """Lifecycle state reported by the startup, liveness and readiness probes"""
+    STARTING = "starting"   # Subsystems still being constructed
+    LIVE = "live"           # Subsystems constructed, startup sequence still running
+    READY = "ready"         # Every readiness check passes
+    DEGRADED = "degraded"   # Startup finished but not every readiness condition holds
+    FAILED = "failed"       # A fatal error; only this state fails the liveness probe
@@ -0,0 +1,59 @@
+import asyncio
+import time
+from typing import Any, Dict, Optional, Tuple
+
+from .access_control import hash_api_key
+
+# Rejections depend on the caller's credentials or quota at that moment, so a retry must run again
+UNCACHED_STATUS_CODES = {401, 403, 429}
+
+
+def idempotency_cache_key(client: str, api_key: str, path: str, idempotency_key: str) -> str:
+    """Cache key scoped to the caller, so one client's key never replays another credential's response"""
+    return f"{client} {hash_api_key(api_key) if api_key else '-'} {path} {idempotency_key}"
+
+
+def is_cacheable(status_code: int) -> bool:
+    """Server errors and credential or quota rejections are not replayed"""
+    return status_code < 500 and status_code not in UNCACHED_STATUS_CODES
+
+
+class IdempotencyCache:
+    """TTL cache of responses keyed by X-Idempotency-Key, so retried requests are not re-executed"""
+    
+    def __init__(self, ttl_seconds: int = 300):
+        self.ttl_seconds = ttl_seconds
+        self.entries: Dict[str, Tuple[float, asyncio.Future]] = {}  # key -> (expires_at, response future)
+    
+    def claim(self, key: str) -> Tuple[bool, asyncio.Future]:
+        """Get the response future for a key; returns (is_new, future), where a new claim must be resolved"""
+        self._prune()
+        
+        entry = self.entries.get(key)
+        if entry is not None:
+            return False, entry[1]
+        
+        future = asyncio.get_running_loop().create_future()
+        self.entries[key] = (time.monotonic() + self.ttl_seconds, future)
+        return True, future
+    
+    async def wait(self, future: asyncio.Future, timeout_seconds: float) -> Optional[Any]:
+        """Wait for the first request's response; raises asyncio.TimeoutError while it is still running"""
+        return await asyncio.wait_for(asyncio.shield(future), timeout_seconds)
+    
+    def resolve(self, key: str, response: Optional[Any]):
+        """Store the response for a claimed key, or forget the key if the request should not be cached"""
+        entry = self.entries.get(key)
+        if entry is None:
+            return
+        
+        if response is None:
+            del self.entries[key]
+        if not entry[1].done():
+            entry[1].set_result(response)
+    
+    def _prune(self):
+        now = time.monotonic()
+        expired = [key for key, (expires_at, future) in self.entries.items() if expires_at < now and future.done()]
+        for key in expired:
+            del self.entries[key]
@@ -0,0 +1,52 @@
+import asyncio
+
+
+class InflightTracker:
+    """Counts in-flight HTTP requests so shutdown can wait for them to finish"""
+    
+    def __init__(self):
+        self.count = 0
+        self._drained = asyncio.Event()
+        self._drained.set()
+    
+    def start(self):
+        """Mark a request as started"""
+        self.count += 1
+        self._drained.clear()
+    
+    def finish(self):
+        """Mark a request as finished"""
+        self.count -= 1
+        if self.count == 0:
+            self._drained.set()
+    
+    async def wait_for_drain(self, timeout_seconds: float) -> bool:
+        """Wait until no requests are in flight; returns False if the timeout expired first"""
+        try:
+            await asyncio.wait_for(self._drained.wait(), timeout_seconds)
+            return True
+        except asyncio.TimeoutError:
+            return False
+
+
+class InflightMiddleware:
+    """Pure ASGI middleware that counts a request as in flight until the app has sent its final body chunk.
+    
+    BaseHTTPMiddleware gets the response back before a streamed body is sent, so it would let shutdown
+    proceed while the client is still receiving data.
+    """
+    
+    def __init__(self, app, tracker: InflightTracker):
+        self.app = app
+        self.tracker = tracker
+    
+    async def __call__(self, scope, receive, send):
+        if scope["type"] != "http":
+            await self.app(scope, receive, send)
+            return
+        
+        self.tracker.start()
+        try:
+            await self.app(scope, receive, send)
+        finally:
+            self.tracker.finish()
@@ -0,0 +1,121 @@
+import bisect
+import time
+from dataclasses import dataclass, field
+from typing import Any, Callable, Dict, List, Optional, Tuple
+
+# A collector returns the current samples of a metric as (labels, value) pairs; histogram values are Histograms
+Collector = Callable[[], List[Tuple[Dict[str, str], Any]]]
+
+# Upper bounds in seconds, spanning fast component creation to slow model construction
+DEFAULT_BUCKETS = (0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0)
+
+
+@dataclass
+class Metric:
+    name: str
+    metric_type: str
+    help: str
+    unit: Optional[str]
+    collect: Collector
+
+
+@dataclass
+class Histogram:
+    """Observations counted into buckets by upper bound, plus their sum and count"""
+    buckets: Tuple[float, ...] = DEFAULT_BUCKETS
+    bucket_counts: List[int] = field(default_factory=list)
+    sum: float = 0.0
+    count: int = 0
+    
+    def __post_init__(self):
+        if not self.bucket_counts:
+            self.bucket_counts = [0] * len(self.buckets)
+    
+    def observe(self, value: float):
+        index = bisect.bisect_left(self.buckets, value)
+        if index < len(self.buckets):
+            self.bucket_counts[index] += 1
+        self.sum += value
+        self.count += 1
+    
+    def cumulative_buckets(self) -> List[Tuple[str, int]]:
+        """(le, count) pairs as exported: each bucket counts every observation at or below its bound"""
+        cumulative, total = [], 0
+        for bound, bucket_count in zip(self.buckets, self.bucket_counts):
+            total += bucket_count
+            cumulative.append((repr(float(bound)), total))
+        cumulative.append(("+Inf", self.count))
+        return cumulative
+
+
+class MetricsRegistry:
+    """Registered metrics, exported in OpenMetrics or Prometheus text format"""
+    
+    def __init__(self):
+        self.metrics: Dict[str, Metric] = {}
+    
+    def register_counter(self, name: str, help: str, collect: Collector, unit: Optional[str] = None):
+        """Register a monotonically increasing metric"""
+        self._register(name, "counter", help, unit, collect)
+    
+    def register_gauge(self, name: str, help: str, collect: Collector, unit: Optional[str] = None):
+        """Register a metric that can go up and down"""
+        self._register(name, "gauge", help, unit, collect)
+    
+    def register_histogram(self, name: str, help: str, collect: Collector, unit: Optional[str] = None):
+        """Register a distribution; the collector returns a Histogram per label set"""
+        self._register(name, "histogram", help, unit, collect)
+    
+    def _register(self, name: str, metric_type: str, help: str, unit: Optional[str], collect: Collector):
+        # OpenMetrics requires the unit as a name suffix
+        if unit and not name.endswith(f"_{unit}"):
+            raise ValueError(f"Metric {name} must end with its unit suffix _{unit}")
+        if name in self.metrics:
+            raise ValueError(f"Metric already registered: {name}")
+        self.metrics[name] = Metric(name, metric_type, help, unit, collect)
+    
+    def export_openmetrics(self) -> str:
+        """Render every metric as an OpenMetrics 1.0 text body"""
+        timestamp = f"{time.time():.3f}"
+        lines = []
+        for metric in self.metrics.values():
+            lines.append(f"# TYPE {metric.name} {metric.metric_type}")
+            lines.append(f"# HELP {metric.name} {metric.help}")
+            if metric.unit:
+                lines.append(f"# UNIT {metric.name} {metric.unit}")
+            for sample_name, labels, value in self._samples(metric):
+                lines.append(f"{sample_name}{self._format_labels(labels)} {value} {timestamp}")
+        lines.append("# EOF")
+        return "\n".join(lines) + "\n"
+    
+    def export_prometheus(self) -> str:
+        """Render every metric in the Prometheus 0.0.4 text format"""
+        lines = []
+        for metric in self.metrics.values():
+            family_name = f"{metric.name}_total" if metric.metric_type == "counter" else metric.name
+            lines.append(f"# HELP {family_name} {metric.help}")
+            lines.append(f"# TYPE {family_name} {metric.metric_type}")
+            for sample_name, labels, value in self._samples(metric):
+                lines.append(f"{sample_name}{self._format_labels(labels)} {value}")
+        return "\n".join(lines) + "\n"
+    
+    def _samples(self, metric: Metric) -> List[Tuple[str, Dict[str, str], Any]]:
+        """Expand a metric's collected values into (sample name, labels, value) lines"""
+        if metric.metric_type == "counter":
+            return [(f"{metric.name}_total", labels, value) for labels, value in metric.collect()]
+        if metric.metric_type != "histogram":
+            return [(metric.name, labels, value) for labels, value in metric.collect()]
+        
+        samples = []
+        for labels, histogram in metric.collect():
+            for le, count in histogram.cumulative_buckets():
+                samples.append((f"{metric.name}_bucket", {**labels, "le": le}, count))
+            samples.append((f"{metric.name}_sum", labels, histogram.sum))
+            samples.append((f"{metric.name}_count", labels, histogram.count))
+        return samples
+    
+    def _format_labels(self, labels: Dict[str, str]) -> str:
+        if not labels:
+            return ""
+        escaped = {k: str(v).replace("\\", "\\\\").replace('"', '\\"').replace("\n", "\\n") for k, v in labels.items()}
+        return "{" + ",".join(f'{k}="{v}"' for k, v in escaped.items()) + "}"
@@ -0,0 +1,31 @@
+import time
+from typing import Dict, Tuple
+
+
+class RateLimiter:
+    """Fixed-window request rate limiter keyed by client"""
+    
+    def __init__(self, max_requests: int, window_seconds: int):
+        self.max_requests = max_requests
+        self.window_seconds = window_seconds
+        self.windows: Dict[str, Tuple[int, int]] = {}  # client -> (window_start, count)
+    
+    def hit(self, client: str) -> Tuple[bool, int, int]:
+        """Record a request; returns (allowed, remaining, reset_unix_timestamp)"""
+        now = int(time.time())
+        window_start = now - (now % self.window_seconds)
+        
+        start, count = self.windows.get(client, (window_start, 0))
+        if start != window_start:
+            start, count = window_start, 0
+        
+        allowed = count < self.max_requests
+        if allowed:
+            count += 1
+        self.windows[client] = (start, count)
+        
+        # Drop windows from idle clients so the map does not grow unbounded
+        if len(self.windows) > 10000:
+            self.windows = {k: v for k, v in self.windows.items() if v[0] == window_start}
+        
+        return allowed, self.max_requests - count, start + self.window_seconds
@@ -0,0 +1,27 @@
+import logging
+from typing import Callable, Dict, List, Tuple
+
+
+class ReadinessChecks:
+    """Named conditions that must all hold before the backend reports ready"""
+    
+    def __init__(self):
+        self._checks: Dict[str, Callable[[], bool]] = {}
+    
+    def register_readiness_check(self, name: str, check: Callable[[], bool]):
+        """Add a readiness condition, replacing any existing check with the same name"""
+        self._checks[name] = check
+        logging.info(f"🩺 Registered readiness check: {name}")
+    
+    def evaluate(self) -> Tuple[bool, List[str]]:
+        """Run every check; returns (all_passed, names_of_failing_checks)"""
+        failing = []
+        for name, check in self._checks.items():
+            try:
+                passed = bool(check())
+            except Exception as e:
+                logging.warning(f"⚠️ Readiness check {name} raised: {e}")
+                passed = False
+            if not passed:
+                failing.append(name)
+        return not failing, failing
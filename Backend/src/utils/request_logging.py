@@ -0,0 +1,88 @@
+import fnmatch
+import logging
+import time
+from enum import Enum
+from typing import Any, Callable, Dict, Optional
+
+from .asgi_capture import BodyCapture, ResponseCapture, redact_secrets, scope_route_path, tee_receive
+
+request_logger = logging.getLogger("archetype.requests")
+
+# Verbose logging keeps this much of each body
+VERBOSE_BODY_MAX_BYTES = 64 * 1024
+
+
+class LogVerbosity(Enum):
+    """How much of a request is logged"""
+    NONE = "none"           # Nothing
+    MINIMAL = "minimal"     # Only error responses (status >= 400)
+    STANDARD = "standard"   # Status code, duration and route for every request
+    VERBOSE = "verbose"     # Standard plus the request and response bodies
+
+
+def resolve_verbosity(path: str, default: LogVerbosity, overrides: Dict[str, LogVerbosity]) -> LogVerbosity:
+    """Get the verbosity for a path; the first matching override pattern (fnmatch) wins"""
+    for pattern, verbosity in overrides.items():
+        if fnmatch.fnmatchcase(path, pattern):
+            return verbosity
+    return default
+
+
+def log_request(verbosity: LogVerbosity, method: str, route: str, status: int, duration_ms: float,
+                request_body: Optional[bytes] = None, response_body: Optional[bytes] = None):
+    """Write one request's log line at the given verbosity"""
+    if verbosity == LogVerbosity.NONE:
+        return
+    if verbosity == LogVerbosity.MINIMAL:
+        if status >= 400:
+            request_logger.warning(f"⚠️ {method} {route} -> {status} ({duration_ms:.1f}ms)")
+        return
+    
+    message = f"{method} {route} -> {status} ({duration_ms:.1f}ms)"
+    if verbosity == LogVerbosity.VERBOSE:
+        message += f"\n  request: {(request_body or b'').decode('utf-8', errors='replace')}"
+        message += f"\n  response: {(response_body or b'').decode('utf-8', errors='replace')}"
+    request_logger.info(message)
+
+
+class RequestLoggingMiddleware:
+    """Pure ASGI middleware logging each request at its path's verbosity.
+    
+    Bodies are copied from the receive and send streams as the app consumes and produces them,
+    so the request body is never read ahead of the endpoint.
+    """
+    
+    def __init__(self, app, settings_provider: Callable[[], Any]):
+        self.app = app
+        self.settings_provider = settings_provider  # current Settings, so SIGHUP reloads apply
+    
+    async def __call__(self, scope, receive, send):
+        if scope["type"] != "http":
+            await self.app(scope, receive, send)
+            return
+        
+        current_settings = self.settings_provider()
+        verbosity = resolve_verbosity(scope["path"], current_settings.default_log_verbosity,
+                                      current_settings.log_verbosity_overrides)
+        if verbosity == LogVerbosity.NONE:
+            await self.app(scope, receive, send)
+            return
+        
+        verbose = verbosity == LogVerbosity.VERBOSE
+        request_body = BodyCapture(VERBOSE_BODY_MAX_BYTES)
+        response = ResponseCapture(BodyCapture(VERBOSE_BODY_MAX_BYTES) if verbose else None)
+        if verbose:
+            receive = tee_receive(receive, request_body)
+        
+        started_at = time.perf_counter()
+        try:
+            await self.app(scope, receive, response.wrap(send))
+        finally:
+            log_request(verbosity, scope["method"], scope_route_path(scope), response.status or 500,
+                        (time.perf_counter() - started_at) * 1000,
+                        _body_preview(request_body) if verbose else None,
+                        _body_preview(response.body) if verbose else None)
+
+
+def _body_preview(capture: BodyCapture) -> bytes:
+    return redact_secrets(capture.body) + (f" ... ({capture.total_bytes} bytes)".encode() if capture.truncated else b"")
@@ -0,0 +1,31 @@
+import fnmatch
+import logging
+from typing import Dict, List, Optional
+
+# Counter key for paths rejected because no allowlist pattern matched
+NOT_ALLOWLISTED = "not_allowlisted"
+
+# Reloads can change the denylist, so the number of counted rules is capped; the rest share one key
+MAX_COUNTED_RULES = 100
+OTHER_RULES = "other"
+
+
+class RouteFilter:
+    """Blocks API paths by configured allow and deny patterns (fnmatch, e.g. /api/v1/plugins/*)"""
+    
+    def __init__(self):
+        # Blocked requests per rule: "deny:<pattern>" or not_allowlisted, never the raw path
+        self.blocked_counts: Dict[str, int] = {}
+    
+    def is_allowed(self, path: str, allowlist: Optional[List[str]], denylist: List[str]) -> bool:
+        """Check a path against the lists; the denylist wins over the allowlist"""
+        rule = next((f"deny:{pattern}" for pattern in denylist if fnmatch.fnmatchcase(path, pattern)), None)
+        if rule is None and allowlist is not None and not any(fnmatch.fnmatchcase(path, p) for p in allowlist):
+            rule = NOT_ALLOWLISTED
+        
+        if rule is not None:
+            if rule not in self.blocked_counts and len(self.blocked_counts) >= MAX_COUNTED_RULES:
+                rule = OTHER_RULES
+            self.blocked_counts[rule] = self.blocked_counts.get(rule, 0) + 1
+            logging.warning(f"⚠️ Blocked request to forbidden route {path} ({rule})")
+        return rule is None
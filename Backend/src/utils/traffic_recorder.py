@@ -0,0 +1,60 @@
+import base64
+import json
+import time
+from typing import Any, Callable, Dict, Optional
+
+from .access_log import AccessLogger
+from .asgi_capture import BodyCapture, ResponseCapture, redact_secrets, scope_path_with_query, tee_receive
+
+# Credentials are never written to the traffic file; secret body fields are masked with redact_secrets
+REDACTED_HEADERS = {"x-api-key", "authorization", "cookie"}
+
+
+class TrafficRecorder(AccessLogger):
+    """Records requests and their responses to a JSON Lines file for later replay"""
+    
+    def record(self, method: str, path: str, headers: Dict[str, str], body: Optional[bytes],
+               status: int, response_body: Optional[bytes]):
+        """Queue one request/response pair; bodies over the size limit are passed as None"""
+        body = redact_secrets(body) if body is not None else None
+        response_body = redact_secrets(response_body) if response_body is not None else None
+        entry = {
+            "timestamp_ms": int(time.time() * 1000),
+            "method": method,
+            "path": path,
+            "headers": {k: v for k, v in headers.items() if k.lower() not in REDACTED_HEADERS},
+            "body_base64": base64.b64encode(body).decode("ascii") if body is not None else None,
+            "response_status": status,
+            "response_body_base64": base64.b64encode(response_body).decode("ascii") if response_body is not None else None,
+        }
+        self.queue.put_nowait(json.dumps(entry))
+
+
+class TrafficRecordingMiddleware:
+    """Pure ASGI middleware copying each request and response to the traffic recorder.
+    
+    Bodies are copied as they stream, up to traffic_record_max_body_bytes; a body over the limit
+    is recorded as None whether or not the client sent a content-length.
+    """
+    
+    def __init__(self, app, recorder_provider: Callable[[], Optional[TrafficRecorder]],
+                 settings_provider: Callable[[], Any]):
+        self.app = app
+        self.recorder_provider = recorder_provider  # None until recording starts at startup
+        self.settings_provider = settings_provider
+    
+    async def __call__(self, scope, receive, send):
+        recorder = self.recorder_provider()
+        if scope["type"] != "http" or recorder is None:
+            await self.app(scope, receive, send)
+            return
+        
+        max_bytes = self.settings_provider().traffic_record_max_body_bytes
+        request_body = BodyCapture(max_bytes)
+        response = ResponseCapture(BodyCapture(max_bytes))
+        await self.app(scope, tee_receive(receive, request_body), response.wrap(send))
+        
+        headers = {key.decode("latin-1"): value.decode("latin-1") for key, value in scope.get("headers", [])}
+        recorder.record(scope["method"], scope_path_with_query(scope), headers,
+                        None if request_body.truncated else request_body.body, response.status or 500,
+                        None if response.body.truncated else response.body.body)
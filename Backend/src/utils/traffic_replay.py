@@ -0,0 +1,75 @@
+import base64
+import json
+import time
+import urllib.error
+import urllib.request
+from pathlib import Path
+from typing import Any, Dict, Optional
+
+# Response fields that differ on every run and are ignored when comparing
+MASKED_FIELDS = {"request_id", "timestamp_ms", "duration_ms", "timestamp", "uptime", "startup_time", "inflight_requests"}
+
+
+def _mask(value: Any) -> Any:
+    if isinstance(value, dict):
+        return {k: "<masked>" if k in MASKED_FIELDS else _mask(v) for k, v in value.items()}
+    if isinstance(value, list):
+        return [_mask(v) for v in value]
+    return value
+
+
+def _comparable(body: Optional[bytes]) -> Any:
+    if body is None:
+        return None
+    try:
+        return _mask(json.loads(body))
+    except ValueError:
+        return body.decode("utf-8", errors="replace")
+
+
+def replay_traffic(traffic_path: Path, base_url: str, speed: float = 1.0,
+                   api_key: Optional[str] = None) -> Dict[str, Any]:
+    """Replay recorded requests against a running server at their original pacing and diff the responses"""
+    with open(traffic_path, 'r', encoding='utf-8') as f:
+        entries = [json.loads(line) for line in f if line.strip()]
+    
+    mismatches = []
+    previous_ms = None
+    for entry in entries:
+        if previous_ms is not None and speed > 0:
+            time.sleep(max(0, entry["timestamp_ms"] - previous_ms) / 1000 / speed)
+        previous_ms = entry["timestamp_ms"]
+        
+        headers = {k: v for k, v in entry["headers"].items() if k.lower() not in ("host", "content-length")}
+        if api_key:
+            headers["X-API-Key"] = api_key
+        body = base64.b64decode(entry["body_base64"]) if entry["body_base64"] is not None else None
+        
+        request = urllib.request.Request(base_url + entry["path"], data=body, headers=headers, method=entry["method"])
+        try:
+            with urllib.request.urlopen(request) as response:
+                status, response_body = response.status, response.read()
+        except urllib.error.HTTPError as e:
+            status, response_body = e.code, e.read()
+        
+        # Bodies over the recording size limit were not stored, so only the status can be compared
+        recorded_body = entry.get("response_body_base64")
+        expected = _comparable(base64.b64decode(recorded_body)) if recorded_body is not None else None
+        actual = _comparable(response_body) if recorded_body is not None else None
+        
+        if status != entry["response_status"] or expected != actual:
+            mismatches.append({
+                "method": entry["method"],
+                "path": entry["path"],
+                "recorded_status": entry["response_status"],
+                "status": status,
+                "recorded_body": expected,
+                "body": actual
+            })
+    
+    return {
+        "total": len(entries),
+        "matched": len(entries) - len(mismatches),
+        "mismatched": len(mismatches),
+        "mismatches": mismatches
+    }
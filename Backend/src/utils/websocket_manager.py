@@ -1,7 +1,8 @@
-from typing import List
+from typing import List, Optional
 from fastapi import WebSocket
 import json
 import logging
+import time
 
 
 class WebSocketManager:
@@ -9,12 +10,29 @@ class WebSocketManager:
     
     def __init__(self):
         self.active_connections: List[WebSocket] = []
+        
+        # Set once every readiness check passes; clients connecting afterwards get the event on connect
+        self.startup_complete_ms: Optional[int] = None
     
     async def connect(self, websocket: WebSocket):
         """Accept new WebSocket connection"""
         await websocket.accept()
         self.active_connections.append(websocket)
         logging.info(f"WebSocket connected. Total connections: {len(self.active_connections)}")
+        
+        if self.startup_complete_ms is not None:
+            await self.send_json({"event": "startup_complete", "timestamp_ms": self.startup_complete_ms}, websocket)
+    
+    async def mark_startup_complete(self):
+        """Tell every connected client the backend is ready, and remember it for later clients"""
+        if self.startup_complete_ms is not None:
+            return
+        self.startup_complete_ms = int(time.time() * 1000)
+        await self.broadcast_json({"event": "startup_complete", "timestamp_ms": self.startup_complete_ms})
+    
+    async def announce_shutdown(self):
+        """Tell every connected client a graceful shutdown has started so they can disconnect cleanly"""
+        await self.broadcast_json({"event": "shutdown_pending", "timestamp_ms": int(time.time() * 1000)})
     
     def disconnect(self, websocket: WebSocket):
         """Remove WebSocket connection"""
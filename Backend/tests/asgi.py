@@ -0,0 +1,51 @@
+"""Minimal ASGI driver for testing pure ASGI middleware without a server"""
+
+from typing import Any, Dict, List, Optional, Tuple
+
+
+def http_scope(method: str = "GET", path: str = "/", query_string: bytes = b"",
+               headers: Optional[List[Tuple[bytes, bytes]]] = None) -> Dict[str, Any]:
+    return {
+        "type": "http",
+        "method": method,
+        "path": path,
+        "query_string": query_string,
+        "headers": headers or [],
+        "client": ("127.0.0.1", 50000),
+    }
+
+
+async def call_asgi(app, scope: Dict[str, Any], body_chunks: Tuple[bytes, ...] = (b"",)) -> Tuple[List[Dict[str, Any]], bytes]:
+    """Run an app with the request body split into chunks; returns the sent messages and the response body"""
+    messages = [
+        {"type": "http.request", "body": chunk, "more_body": index < len(body_chunks) - 1}
+        for index, chunk in enumerate(body_chunks)
+    ]
+    sent: List[Dict[str, Any]] = []
+
+    async def receive():
+        return messages.pop(0) if messages else {"type": "http.disconnect"}
+
+    async def send(message):
+        sent.append(message)
+
+    await app(scope, receive, send)
+    return sent, b"".join(m.get("body", b"") for m in sent if m["type"] == "http.response.body")
+
+
+def echo_app(status: int = 200, response_chunks: int = 1):
+    """ASGI app that reads the whole request body and sends it back"""
+    async def app(scope, receive, send):
+        body = b""
+        while True:
+            message = await receive()
+            body += message.get("body", b"")
+            if not message.get("more_body"):
+                break
+
+        await send({"type": "http.response.start", "status": status, "headers": [(b"content-type", b"text/plain")]})
+        step = max(1, len(body) // response_chunks)
+        parts = [body[i:i + step] for i in range(0, len(body), step)] or [b""]
+        for index, part in enumerate(parts):
+            await send({"type": "http.response.body", "body": part, "more_body": index < len(parts) - 1})
+    return app
@@ -0,0 +1,49 @@
+import os
+
+import pytest
+
+from src.utils.config import Settings
+from tests.mocks import MockGpuBackend, MockPluginBackend, write_gpu_mock_file
+
+
+@pytest.fixture
+def mock_gpu(tmp_path):
+    return MockGpuBackend(write_gpu_mock_file(tmp_path / "gpu_mock.json"))
+
+
+@pytest.fixture
+def mock_plugins(tmp_path):
+    return MockPluginBackend(tmp_path / "plugins", log_directory=tmp_path / "plugin_logs")
+
+
+@pytest.fixture
+def test_settings(tmp_path):
+    return Settings(
+        _env_file=None,
+        plugin_directory=tmp_path / "plugins",
+        profile_directory=tmp_path / "profiles",
+        feature_flags_path=tmp_path / "feature_flags.json",
+        access_control_path=tmp_path / "access_control.json",
+    )
+
+
+@pytest.fixture(scope="session")
+def backend_main(tmp_path_factory):
+    """The main module, imported from a scratch directory so module-level setup does not write into the repo"""
+    workdir = tmp_path_factory.mktemp("backend")
+    (workdir / "static").mkdir()
+    previous = os.getcwd()
+    os.chdir(workdir)
+    try:
+        import main
+    finally:
+        os.chdir(previous)
+    return main
+
+
+@pytest.fixture
+def client(backend_main, mock_gpu, mock_plugins, test_settings):
+    from fastapi.testclient import TestClient
+
+    backend_main.init_state_for_testing(mock_gpu, mock_plugins, test_settings)
+    return TestClient(backend_main.app)
@@ -0,0 +1,126 @@
+"""Mock GPU and plugin backends for tests that run without GPUs or the core plugins"""
+
+import functools
+import inspect
+import json
+from collections import Counter
+from pathlib import Path
+from typing import Any, Dict
+
+from src.core.gpu_manager import UniversalGPUManager
+from src.plugins.plugin_manager import PluginManager
+
+MOCK_GPU_DATA: Dict[str, Any] = {
+    "selected_device_id": "cuda:0",
+    "devices": [
+        {
+            "id": "cuda:0",
+            "name": "Mock RTX 4090",
+            "vendor": "NVIDIA",
+            "type": "cuda",
+            "memory_mb": 24576,
+            "compute_units": 128,
+            "performance_score": 950,
+            "is_discrete": True,
+            "compute_capability": "8.9",
+        },
+        {
+            "id": "cpu",
+            "name": "Mock CPU",
+            "vendor": "Unknown",
+            "type": "cpu",
+            "memory_mb": 32768,
+            "compute_units": 16,
+            "performance_score": 100,
+        },
+    ],
+    "mock_outputs": {
+        "get_device_status": {"status": "ok", "temperature_c": 45.0},
+        "run_benchmark": {"overall_gflops": 1000.0, "results": []},
+        "measure_memory_bandwidth": {"upload_bandwidth_gbps": 24.0, "download_bandwidth_gbps": 22.0},
+    },
+}
+
+BASIC_PLUGIN_SOURCE = '''
+from src.plugins.plugin_manager import PluginBase
+
+
+class Plugin(PluginBase):
+    def get_manifest(self):
+        return {}
+
+    async def initialize(self):
+        return True
+
+    async def cleanup(self):
+        pass
+
+    def get_neural_component_types(self):
+        return ["echo"]
+
+    async def create_component(self, component_type, config):
+        return dict(config)
+'''
+
+
+class CallCounter:
+    """Counts calls to public methods in self.calls, keyed by method name"""
+
+    def __getattribute__(self, name: str):
+        attr = object.__getattribute__(self, name)
+        if name.startswith("_") or not inspect.ismethod(attr):
+            return attr
+
+        calls = object.__getattribute__(self, "calls")
+        if inspect.iscoroutinefunction(attr):
+            @functools.wraps(attr)
+            async def counted_async(*args, **kwargs):
+                calls[name] += 1
+                return await attr(*args, **kwargs)
+            return counted_async
+
+        @functools.wraps(attr)
+        def counted(*args, **kwargs):
+            calls[name] += 1
+            return attr(*args, **kwargs)
+        return counted
+
+
+class MockGpuBackend(CallCounter, UniversalGPUManager):
+    """UniversalGPUManager reading its devices from a generated mock file"""
+
+    def __init__(self, mock_file: Path):
+        self.calls: Counter = Counter()
+        super().__init__(mock_file=mock_file)
+
+
+class MockPluginBackend(CallCounter, PluginManager):
+    """PluginManager over a scratch directory with no core plugins"""
+
+    def __init__(self, plugin_directory: Path, log_directory: Path):
+        self.calls: Counter = Counter()
+        super().__init__(plugin_directory, log_directory=log_directory)
+        self.core_plugin_modules = {}
+
+    def add_plugin(self, plugin_id: str, source: str = BASIC_PLUGIN_SOURCE, **manifest) -> Path:
+        """Write an extension plugin into this backend's extension directory"""
+        return write_plugin(self.extension_plugin_directory, plugin_id, source, **manifest)
+
+
+def write_gpu_mock_file(path: Path, data: Dict[str, Any] = MOCK_GPU_DATA) -> Path:
+    path.write_text(json.dumps(data))
+    return path
+
+
+def write_plugin(extension_directory: Path, plugin_id: str, source: str = BASIC_PLUGIN_SOURCE, **manifest) -> Path:
+    """Write plugin.py and manifest.json for an extension plugin; extra keywords go into the manifest"""
+    plugin_path = Path(extension_directory) / plugin_id
+    plugin_path.mkdir(parents=True, exist_ok=True)
+    (plugin_path / "plugin.py").write_text(source)
+    (plugin_path / "manifest.json").write_text(json.dumps({
+        "id": plugin_id,
+        "name": plugin_id,
+        "version": "1.0.0",
+        **manifest,
+    }))
+    return plugin_path
@@ -0,0 +1,68 @@
+import pytest
+
+from src.plugins.plugin_manager import PluginDependentsLoaded, PluginInABTest
+from tests.mocks import BASIC_PLUGIN_SOURCE
+
+VARIANT_SOURCE = BASIC_PLUGIN_SOURCE.replace("return dict(config)", 'return dict(config, variant=True)')
+
+
+async def start_ab_test(mock_plugins, **variant_manifest):
+    mock_plugins.add_plugin("ab.main")
+    mock_plugins.add_plugin("ab.variant", VARIANT_SOURCE, **variant_manifest)
+    for plugin_id in ("ab.main", "ab.variant"):
+        await mock_plugins.load_plugin(plugin_id)
+        await mock_plugins.enable_plugin(plugin_id)
+    await mock_plugins.enable_ab_test("ab.main", "ab.variant", traffic_split=0.5)
+
+
+async def test_promoted_variant_takes_over_the_original_id(mock_plugins):
+    await start_ab_test(mock_plugins, call_graph={"calls_gpu": True})
+
+    await mock_plugins.promote_ab_version("ab.main")
+
+    promoted = mock_plugins.loaded_plugins["ab.main"]
+    assert "ab.variant" not in mock_plugins.loaded_plugins
+    assert promoted.logger.name == "archetype.plugins.ab.main"
+    assert "ab.variant" not in mock_plugins.plugin_call_graphs
+    assert mock_plugins.plugin_call_graphs["ab.main"]["calls_gpu"] is True
+    assert await mock_plugins.create_neural_component("ab.main", "echo", {}) == {"variant": True}
+
+    promoted.services.request_service("broadcast_channel")
+    assert [v["plugin_id"] for v in mock_plugins.call_graph_violations] == ["ab.main"]
+
+
+async def test_promote_refuses_while_other_plugins_depend_on_the_variant(mock_plugins):
+    await start_ab_test(mock_plugins)
+    mock_plugins.add_plugin("ab.user", dependencies=["ab.variant"])
+    await mock_plugins.load_plugin("ab.user")
+
+    with pytest.raises(PluginDependentsLoaded) as excinfo:
+        await mock_plugins.promote_ab_version("ab.main")
+    assert excinfo.value.dependents == ["ab.user"]
+    assert "ab.variant" in mock_plugins.loaded_plugins
+    assert "ab.main" in mock_plugins.ab_tests
+
+    await mock_plugins.promote_ab_version("ab.main", force=True)
+    assert "ab.variant" not in mock_plugins.loaded_plugins
+
+
+@pytest.mark.parametrize("arm", ["ab.main", "ab.variant"])
+async def test_unload_refuses_an_arm_of_a_running_test(mock_plugins, arm):
+    await start_ab_test(mock_plugins)
+
+    with pytest.raises(PluginInABTest, match="A/B test on ab.main"):
+        await mock_plugins.unload_plugin(arm)
+    assert arm in mock_plugins.loaded_plugins
+
+    await mock_plugins.unload_plugin(arm, force=True)
+    assert "ab.main" not in mock_plugins.ab_tests
+    assert arm not in mock_plugins.plugin_call_graphs
+
+
+async def test_reloading_an_arm_keeps_the_test_running(mock_plugins):
+    await start_ab_test(mock_plugins)
+
+    await mock_plugins.reload_plugin("ab.variant")
+
+    assert mock_plugins.ab_tests["ab.main"]["variant_id"] == "ab.variant"
+    assert "ab.variant" in mock_plugins.plugin_call_graphs
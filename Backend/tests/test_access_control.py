@@ -0,0 +1,96 @@
+import json
+
+import pytest
+
+from src.utils.access_control import AccessControl, hash_api_key
+
+PERMISSIONS = {
+    "viewer": ["GET /api/v1/config/*", "GET /api/v1/gpu"],
+    "operator": ["POST /api/v1/gpu/*"],
+}
+
+
+def write_access_control(path):
+    path.write_text(json.dumps({
+        "users": [
+            {"name": "vera", "api_key_hash": hash_api_key("viewer-key"), "roles": ["viewer"]},
+            {"name": "otto", "api_key_hash": hash_api_key("operator-key"), "roles": ["viewer", "operator"]},
+        ],
+        "permissions": PERMISSIONS,
+    }))
+    return path
+
+
+@pytest.fixture
+def access_control(tmp_path):
+    return AccessControl(write_access_control(tmp_path / "access_control.json"), admin_api_key="admin-key")
+
+
+@pytest.fixture
+def secured_client(backend_main, mock_gpu, mock_plugins, test_settings):
+    from fastapi.testclient import TestClient
+
+    write_access_control(test_settings.access_control_path)
+    test_settings.rate_limit_requests = 3
+    test_settings.rate_limit_window_seconds = 3600
+    backend_main.init_state_for_testing(mock_gpu, mock_plugins, test_settings)
+    return TestClient(backend_main.app)
+
+
+def test_roles_resolve_from_key_hashes(access_control):
+    assert access_control.enabled
+    assert access_control.resolve_roles("viewer-key") == ["viewer"]
+    assert access_control.resolve_roles("admin-key") == ["admin"]
+    assert access_control.resolve_roles("unknown-key") is None
+    assert access_control.resolve_roles("") is None
+
+
+def test_missing_or_unknown_key_is_unauthorized(access_control):
+    assert access_control.authorize("", "GET", "/api/v1/gpu")[0] == 401
+    assert access_control.authorize("unknown-key", "GET", "/api/v1/gpu")[0] == 401
+
+
+def test_role_without_the_permission_is_forbidden_and_told_which_roles_would_do(access_control):
+    status_code, error = access_control.authorize("viewer-key", "POST", "/api/v1/gpu/select-device")
+
+    assert status_code == 403
+    assert error["command"] == "POST /api/v1/gpu/select-device"
+    assert error["required_roles"] == ["admin", "operator"]
+
+
+@pytest.mark.parametrize("api_key, method, path", [
+    ("viewer-key", "GET", "/api/v1/config/environment"),
+    ("operator-key", "POST", "/api/v1/gpu/select-device"),
+    ("admin-key", "DELETE", "/api/v1/users/vera"),
+    ("", "GET", "/api/v1/health/live"),
+])
+def test_permitted_requests_are_allowed(access_control, api_key, method, path):
+    assert access_control.authorize(api_key, method, path) == (200, None)
+
+
+def test_users_are_stored_without_their_keys(access_control):
+    access_control.add_user("nina", "new-key", ["viewer"])
+
+    stored = access_control.path.read_text()
+    assert "new-key" not in stored
+    assert AccessControl(access_control.path).resolve_roles("new-key") == ["viewer"]
+    access_control.revoke_user("nina")
+    assert access_control.resolve_roles("new-key") is None
+
+
+def test_http_requests_are_checked_against_roles(secured_client):
+    assert secured_client.get("/api/v1/config/environment").status_code == 401
+    assert secured_client.get("/api/v1/config/environment", headers={"X-API-Key": "viewer-key"}).status_code == 200
+
+    forbidden = secured_client.post("/api/v1/gpu/select-device", json={"device_id": "cpu"},
+                                    headers={"X-API-Key": "viewer-key"})
+    assert forbidden.status_code == 403
+    assert forbidden.json()["required_roles"] == ["admin", "operator"]
+
+
+def test_rejected_requests_count_against_the_rate_limit(secured_client):
+    rejected = [secured_client.get("/api/v1/gpu", headers={"X-API-Key": f"guess-{n}"}) for n in range(3)]
+
+    assert [r.status_code for r in rejected] == [401, 401, 401]
+    assert [r.headers["x-rate-limit-remaining"] for r in rejected] == ["2", "1", "0"]
+    assert secured_client.get("/api/v1/gpu", headers={"X-API-Key": "viewer-key"}).status_code == 429
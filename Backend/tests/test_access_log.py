@@ -0,0 +1,54 @@
+import pytest
+
+from src.utils.access_log import AccessLogger, AccessLogMiddleware
+from tests.asgi import call_asgi, echo_app, http_scope
+
+
+def logged_lines(access_logger):
+    return [access_logger.queue.get_nowait() for _ in range(access_logger.queue.qsize())]
+
+
+async def test_streamed_response_bytes_are_counted(tmp_path):
+    access_logger = AccessLogger(tmp_path / "access.log")
+    app = AccessLogMiddleware(echo_app(response_chunks=3), logger_provider=lambda: access_logger)
+
+    await call_asgi(app, http_scope("POST", "/api/v1/compute", b"n=1"), (b"x" * 30,))
+
+    [line] = logged_lines(access_logger)
+    assert line.startswith("127.0.0.1 - - [")
+    assert line.endswith('"POST /api/v1/compute?n=1 HTTP/1.1" 200 30')
+
+
+async def test_failed_request_is_still_logged(tmp_path):
+    access_logger = AccessLogger(tmp_path / "access.log")
+
+    async def failing_app(scope, receive, send):
+        raise RuntimeError("boom")
+
+    with pytest.raises(RuntimeError):
+        await call_asgi(AccessLogMiddleware(failing_app, logger_provider=lambda: access_logger), http_scope())
+
+    [line] = logged_lines(access_logger)
+    assert line.endswith('"GET / HTTP/1.1" 500 -')
+
+
+async def test_protocol_comes_from_the_scope(tmp_path):
+    access_logger = AccessLogger(tmp_path / "access.log")
+    scope = dict(http_scope(), http_version="2")
+
+    await call_asgi(AccessLogMiddleware(echo_app(), logger_provider=lambda: access_logger), scope)
+
+    [line] = logged_lines(access_logger)
+    assert '"GET / HTTP/2" 200 0' in line
+
+
+async def test_writer_thread_flushes_queued_lines_on_stop(tmp_path):
+    access_logger = AccessLogger(tmp_path / "logs" / "access.log", flush_interval_seconds=60)
+    access_logger.start()
+
+    for status in (200, 404):
+        access_logger.log("127.0.0.1", "GET", "/", status, 0)
+    await access_logger.stop()
+
+    lines = access_logger.path.read_text().splitlines()
+    assert [line.rsplit(" ", 2)[1] for line in lines] == ["200", "404"]
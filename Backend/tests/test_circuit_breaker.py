@@ -0,0 +1,35 @@
+import asyncio
+from types import SimpleNamespace
+
+import pytest
+
+from src.utils.circuit_breaker import CircuitBreaker, CircuitState
+
+
+def half_open_breaker():
+    breaker = CircuitBreaker("gpu", failure_threshold=1, cooldown_seconds=0)
+    breaker.record_failure()
+    return breaker
+
+
+def test_half_open_breaker_allows_a_single_probe():
+    breaker = half_open_breaker()
+
+    assert breaker.allow_request()
+    assert breaker.state == CircuitState.HALF_OPEN
+    assert not breaker.allow_request()
+
+
+async def test_cancelled_probe_releases_the_breaker(backend_main, monkeypatch):
+    breaker = half_open_breaker()
+    monkeypatch.setattr(backend_main, "circuit_breakers", {"gpu": breaker})
+    request = SimpleNamespace(url=SimpleNamespace(path="/api/v1/gpu"))
+
+    async def cancelled(request):
+        raise asyncio.CancelledError()
+
+    with pytest.raises(asyncio.CancelledError):
+        await backend_main.circuit_breaker_middleware(request, cancelled)
+
+    assert breaker.state == CircuitState.OPEN
+    assert breaker.allow_request()
@@ -0,0 +1,66 @@
+import logging
+
+import pytest
+
+from src.utils.config import Settings
+
+
+@pytest.fixture(autouse=True)
+def clean_environment(monkeypatch):
+    for name in ("PORT", "ARCHETYPE_PORT", "LOG_LEVEL", "ARCHETYPE_LOG_LEVEL", "HOST", "ARCHETYPE_HOST"):
+        monkeypatch.delenv(name, raising=False)
+
+
+def test_prefixed_environment_variable_overrides_env_file(tmp_path, monkeypatch):
+    env_file = tmp_path / ".env"
+    env_file.write_text("ARCHETYPE_PORT=9000\nARCHETYPE_HOST=0.0.0.0\n")
+    monkeypatch.setenv("ARCHETYPE_PORT", "9100")
+
+    settings = Settings(_env_file=env_file)
+
+    assert settings.port == 9100
+    assert settings.host == "0.0.0.0"
+
+
+def test_unprefixed_names_still_apply_with_a_warning(tmp_path, monkeypatch, caplog):
+    env_file = tmp_path / ".env"
+    env_file.write_text("HOST=0.0.0.0\n")
+    monkeypatch.setenv("PORT", "9200")
+
+    with caplog.at_level(logging.WARNING):
+        settings = Settings(_env_file=env_file)
+
+    assert settings.port == 9200
+    assert settings.host == "0.0.0.0"
+    assert "PORT is deprecated; use ARCHETYPE_PORT" in caplog.text
+    assert "HOST is deprecated; use ARCHETYPE_HOST" in caplog.text
+
+
+def test_prefixed_names_win_over_unprefixed_ones(tmp_path, monkeypatch):
+    env_file = tmp_path / ".env"
+    env_file.write_text("LOG_LEVEL=debug\nARCHETYPE_LOG_LEVEL=warning\n")
+    monkeypatch.setenv("PORT", "9300")
+    monkeypatch.setenv("ARCHETYPE_PORT", "9400")
+
+    settings = Settings(_env_file=env_file)
+
+    assert settings.port == 9400
+    assert settings.log_level == "warning"
+
+
+def test_printed_config_masks_the_admin_key():
+    printed = Settings(_env_file=None, admin_api_key="hunter2").redacted_json()
+
+    assert "hunter2" not in printed
+    assert '"admin_api_key": "**********"' in printed
+    assert '"admin_api_key": null' in Settings(_env_file=None).redacted_json()
+
+
+def test_copy_without_secrets_keeps_everything_else():
+    settings = Settings(_env_file=None, admin_api_key="hunter2", port=9000)
+
+    public = settings.without_secrets()
+
+    assert public.admin_api_key is None
+    assert public.port == 9000
+    assert settings.admin_api_key == "hunter2"
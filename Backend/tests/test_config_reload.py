@@ -0,0 +1,68 @@
+import asyncio
+import logging
+import os
+import signal
+from types import SimpleNamespace
+
+import pytest
+
+from src.utils.circuit_breaker import CircuitBreaker
+from src.utils.config import Settings
+from src.utils.config_reload import reload_settings
+
+
+@pytest.fixture
+def state(tmp_path, monkeypatch):
+    monkeypatch.chdir(tmp_path)  # no .env file
+    for name in ("ARCHETYPE_LOG_LEVEL", "LOG_LEVEL", "ARCHETYPE_RECORD_TRAFFIC", "ARCHETYPE_CIRCUIT_FAILURE_THRESHOLD",
+                 "ARCHETYPE_ADMIN_API_KEY", "ADMIN_API_KEY"):
+        monkeypatch.delenv(name, raising=False)
+    return SimpleNamespace(settings=Settings(), circuit_breakers={"gpu": CircuitBreaker("gpu")})
+
+
+@pytest.fixture
+def root_log_level():
+    level = logging.getLogger().level
+    yield
+    logging.getLogger().setLevel(level)
+
+
+def test_startup_only_fields_are_reported_as_restart_required(state, monkeypatch):
+    monkeypatch.setenv("ARCHETYPE_RECORD_TRAFFIC", "true")
+    monkeypatch.setenv("ARCHETYPE_CIRCUIT_FAILURE_THRESHOLD", "2")
+
+    report = reload_settings(state)
+
+    assert report == {"applied": ["circuit_failure_threshold"], "restart_required": ["record_traffic"]}
+    assert state.settings.record_traffic is False
+    assert state.circuit_breakers["gpu"].failure_threshold == 2
+
+
+@pytest.mark.skipif(not hasattr(signal, "SIGHUP"), reason="SIGHUP is POSIX only")
+async def test_sighup_changes_the_log_level(state, monkeypatch, root_log_level):
+    loop = asyncio.get_running_loop()
+    loop.add_signal_handler(signal.SIGHUP, reload_settings, state)
+    monkeypatch.setenv("ARCHETYPE_LOG_LEVEL", "error")
+    try:
+        os.kill(os.getpid(), signal.SIGHUP)
+        for _ in range(100):
+            if state.settings.log_level == "error":
+                break
+            await asyncio.sleep(0.01)
+    finally:
+        loop.remove_signal_handler(signal.SIGHUP)
+
+    assert logging.getLogger().level == logging.ERROR
+
+
+def test_plugins_get_the_reloaded_config_without_secrets(state, monkeypatch, mock_plugins):
+    state.plugin_manager = mock_plugins
+    monkeypatch.setenv("ARCHETYPE_ADMIN_API_KEY", "hunter2")
+    monkeypatch.setenv("ARCHETYPE_CIRCUIT_FAILURE_THRESHOLD", "2")
+
+    reload_settings(state)
+
+    assert state.settings.admin_api_key == "hunter2"
+    config_store = mock_plugins.services.request_service("config_store")
+    assert config_store.admin_api_key is None
+    assert config_store.circuit_failure_threshold == 2
@@ -0,0 +1,13 @@
+import sys
+
+
+async def test_missing_namespaced_dependency_is_reported(mock_plugins):
+    mock_plugins.add_plugin("dry.consumer", dependencies=["acme.codec", "dry.provider", "json.decoder", "json"])
+    mock_plugins.add_plugin("dry.provider")
+
+    report = await mock_plugins.dry_run_load("dry.consumer")
+
+    assert report.missing_dependencies == ["acme.codec"]
+    assert not report.dependencies_satisfied
+    assert "acme" not in sys.modules
+    assert "dry" not in sys.modules
@@ -0,0 +1,24 @@
+def test_gpu_info_is_not_resent_until_the_selection_changes(client):
+    first = client.get("/api/v1/gpu")
+    etag = first.headers["etag"]
+    assert first.status_code == 200
+    assert first.json()["total_devices"] == 2
+
+    repeat = client.get("/api/v1/gpu", headers={"If-None-Match": etag})
+    assert repeat.status_code == 304
+    assert repeat.content == b""
+
+    assert client.post("/api/v1/gpu/select-device", json={"device_id": "cpu"}).status_code == 200
+    changed = client.get("/api/v1/gpu", headers={"If-None-Match": etag})
+    assert changed.status_code == 200
+    assert changed.headers["etag"] != etag
+
+
+def test_enveloped_responses_do_not_carry_the_unwrapped_etag(client, test_settings):
+    test_settings.enable_response_envelope = True
+
+    response = client.get("/api/v1/gpu")
+
+    assert response.status_code == 200
+    assert "data" in response.json()
+    assert "etag" not in response.headers
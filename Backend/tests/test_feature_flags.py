@@ -0,0 +1,48 @@
+import json
+
+import pytest
+
+from src.utils.feature_flags import FeatureFlags
+
+
+def test_http_uploads_blocks_requests_with_a_body(tmp_path):
+    flags = FeatureFlags(tmp_path / "feature_flags.json")
+    flags.set_flag("http_uploads", False, changed_by="test")
+
+    assert flags.disabled_feature_for_request("POST", "/api/v1/training/start", {"content-length": "42"}) == "http_uploads"
+    assert flags.disabled_feature_for_request("PUT", "/api/v1/models/m1", {"transfer-encoding": "chunked"}) == "http_uploads"
+    assert flags.disabled_feature_for_request("POST", "/api/v1/features/http_uploads", {"content-length": "0"}) is None
+    assert flags.disabled_feature_for_request("GET", "/api/v1/gpu", {}) is None
+
+
+def test_http_uploads_flag_persists(tmp_path):
+    FeatureFlags(tmp_path / "feature_flags.json").set_flag("http_uploads", False)
+
+    reloaded = FeatureFlags(tmp_path / "feature_flags.json")
+
+    assert reloaded.flags["http_uploads"] is False
+    assert reloaded.disabled_feature_for_request("POST", "/api/v1/plugins/x.y/load", {"content-length": "2"}) == "http_uploads"
+
+
+def test_route_features_still_apply(tmp_path):
+    flags = FeatureFlags(tmp_path / "feature_flags.json")
+    flags.set_flag("gpu", False)
+
+    assert flags.disabled_feature_for_request("GET", "/api/v1/gpu/settings", {}) == "gpu"
+    assert flags.disabled_feature_for_request("POST", "/api/v1/gpu/select-device", {"content-length": "20"}) == "gpu"
+    assert flags.disabled_feature_for_request("GET", "/api/v1/plugins", {}) is None
+
+
+def test_failed_write_leaves_the_flags_file_intact(tmp_path, monkeypatch):
+    path = tmp_path / "feature_flags.json"
+    FeatureFlags(path).set_flag("gpu", False)
+    before = path.read_text()
+
+    def crash(*args, **kwargs):
+        raise OSError("disk full")
+
+    monkeypatch.setattr(json, "dump", crash)
+    with pytest.raises(OSError):
+        FeatureFlags(path).set_flag("plugins", False)
+
+    assert path.read_text() == before
@@ -0,0 +1,31 @@
+import pytest
+
+from src.core.gpu_manager import MAX_BANDWIDTH_TEST_BYTES, UniversalGPUManager
+from tests.mocks import MOCK_GPU_DATA, write_gpu_mock_file
+
+
+async def test_bandwidth_test_size_is_bounded(mock_gpu):
+    with pytest.raises(ValueError, match="size_bytes must be between"):
+        await mock_gpu.measure_memory_bandwidth(MAX_BANDWIDTH_TEST_BYTES + 1)
+
+
+async def test_mock_outputs_answer_compute_calls(mock_gpu):
+    measurement = await mock_gpu.measure_memory_bandwidth(1024)
+
+    assert measurement.size_bytes == 1024
+    assert measurement.upload_bandwidth_gbps == 24.0
+    assert measurement.device == mock_gpu.get_device_info()
+    assert (await mock_gpu.run_benchmark())["overall_gflops"] == 1000.0
+    assert (await mock_gpu.run_benchmark([64]))["matrix_sizes"] == [64]
+    assert (await mock_gpu.get_device_status())["temperature_c"] == 45.0
+
+
+async def test_compute_calls_without_a_mock_output_run_for_real(tmp_path):
+    data = dict(MOCK_GPU_DATA, selected_device_id="cpu", mock_outputs={})
+    gpu = UniversalGPUManager(mock_file=write_gpu_mock_file(tmp_path / "gpu_mock.json", data))
+
+    status = await gpu.get_device_status()
+
+    assert status["device_id"] == "cpu"
+    with pytest.raises(ValueError, match="needs a GPU device"):
+        await gpu.measure_memory_bandwidth(1024)
@@ -0,0 +1,43 @@
+from src.utils.health_state import HealthState
+from src.utils.readiness import ReadinessChecks
+
+
+def test_liveness_passes_until_a_fatal_error(client, backend_main):
+    for state in (HealthState.STARTING, HealthState.LIVE, HealthState.READY, HealthState.DEGRADED):
+        backend_main.app.state.health_state = state
+        assert client.get("/api/v1/health/live").status_code == 200
+
+    backend_main.app.state.health_state = HealthState.FAILED
+    assert client.get("/api/v1/health/live").status_code == 503
+
+
+def test_readiness_does_not_change_the_health_state(client, backend_main):
+    backend_main.app.state.readiness_checks.register_readiness_check("never", lambda: False)
+
+    response = client.get("/api/v1/health/ready")
+
+    assert response.status_code == 503
+    assert response.json()["failing_checks"] == ["never"]
+    assert backend_main.app.state.health_state == HealthState.READY
+
+
+def test_readiness_follows_a_registered_check(client, backend_main):
+    condition = {"met": False}
+    backend_main.app.state.readiness_checks.register_readiness_check("warmed_up", lambda: condition["met"])
+
+    blocked = client.get("/api/v1/health/ready")
+    assert blocked.status_code == 503
+    assert blocked.json() == {"state": "degraded", "failing_checks": ["warmed_up"]}
+
+    condition["met"] = True
+    ready = client.get("/api/v1/health/ready")
+    assert ready.status_code == 200
+    assert ready.json() == {"state": "ready", "failing_checks": []}
+
+
+def test_raising_readiness_checks_count_as_failing():
+    checks = ReadinessChecks()
+    checks.register_readiness_check("passes", lambda: True)
+    checks.register_readiness_check("raises", lambda: 1 / 0)
+
+    assert checks.evaluate() == (False, ["raises"])
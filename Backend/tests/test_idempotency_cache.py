@@ -0,0 +1,59 @@
+import asyncio
+
+import pytest
+
+from src.utils.idempotency_cache import IdempotencyCache, idempotency_cache_key, is_cacheable
+
+
+def test_cache_key_is_scoped_to_the_api_key():
+    first = idempotency_cache_key("127.0.0.1", "key-a", "/api/v1/compute", "retry-1")
+    second = idempotency_cache_key("127.0.0.1", "key-b", "/api/v1/compute", "retry-1")
+
+    assert first != second
+    assert "key-a" not in first
+
+
+def test_rejections_and_server_errors_are_not_cacheable():
+    assert is_cacheable(200)
+    assert is_cacheable(404)
+    assert not any(is_cacheable(status) for status in (401, 403, 429, 500, 503))
+
+
+async def test_uncached_response_releases_the_key():
+    cache = IdempotencyCache(ttl_seconds=60)
+    is_new, _ = cache.claim("k")
+    cache.resolve("k", None)
+
+    assert is_new
+    assert cache.claim("k")[0]
+
+
+async def test_waiter_times_out_while_the_first_request_runs():
+    cache = IdempotencyCache(ttl_seconds=60)
+    _, future = cache.claim("slow")
+
+    with pytest.raises(asyncio.TimeoutError):
+        await cache.wait(future, 0.01)
+
+    # The timeout must not cancel the first request's future
+    cache.resolve("slow", (200, b"{}", {}))
+    assert await cache.wait(future, 0.01) == (200, b"{}", {})
+
+
+async def test_cancelled_request_releases_waiters():
+    cache = IdempotencyCache(ttl_seconds=60)
+
+    async def first_request():
+        _, future = cache.claim("cancelled")
+        try:
+            await asyncio.sleep(10)
+        finally:
+            cache.resolve("cancelled", None)
+
+    task = asyncio.create_task(first_request())
+    await asyncio.sleep(0)
+    _, waiter = cache.claim("cancelled")
+    task.cancel()
+
+    assert await cache.wait(waiter, 1.0) is None
+    assert "cancelled" not in cache.entries
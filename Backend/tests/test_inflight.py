@@ -0,0 +1,56 @@
+import asyncio
+
+from src.utils.inflight_tracker import InflightMiddleware, InflightTracker
+from tests.asgi import call_asgi, http_scope
+
+
+def slow_streaming_app(chunks: int, delay: float):
+    async def app(scope, receive, send):
+        await send({"type": "http.response.start", "status": 200, "headers": []})
+        for index in range(chunks):
+            await asyncio.sleep(delay)
+            await send({"type": "http.response.body", "body": b"chunk", "more_body": index < chunks - 1})
+    return app
+
+
+async def test_streamed_request_stays_in_flight_until_the_last_chunk():
+    tracker = InflightTracker()
+    request = asyncio.create_task(call_asgi(InflightMiddleware(slow_streaming_app(3, 0.05), tracker), http_scope()))
+    await asyncio.sleep(0.01)
+
+    assert tracker.count == 1
+    assert await tracker.wait_for_drain(2)
+    assert request.done()
+    _, body = request.result()
+    assert body == b"chunk" * 3
+    assert tracker.count == 0
+
+
+async def test_drain_times_out_while_a_request_is_still_running():
+    tracker = InflightTracker()
+    request = asyncio.create_task(call_asgi(InflightMiddleware(slow_streaming_app(1, 0.5), tracker), http_scope()))
+    await asyncio.sleep(0.01)
+
+    assert not await tracker.wait_for_drain(0.05)
+    await request
+    assert tracker.count == 0
+
+
+async def test_shutdown_waits_for_a_slow_handler_before_closing_connections(backend_main, monkeypatch):
+    import uvicorn
+
+    closed_with_inflight = []
+
+    async def close_connections(self, sockets=None):
+        closed_with_inflight.append(backend_main.inflight_tracker.count)
+
+    monkeypatch.setattr(uvicorn.Server, "shutdown", close_connections)
+    slow_app = InflightMiddleware(slow_streaming_app(2, 0.05), backend_main.inflight_tracker)
+    request = asyncio.create_task(call_asgi(slow_app, http_scope()))
+    await asyncio.sleep(0.01)
+
+    await backend_main.ArchetypeServer(uvicorn.Config(backend_main.app)).shutdown()
+
+    assert closed_with_inflight == [0]
+    _, body = await request
+    assert body == b"chunk" * 2
@@ -0,0 +1,22 @@
+import pytest
+
+
+async def test_lazy_plugin_loads_on_its_first_call(mock_plugins):
+    mock_plugins.add_plugin("lazy.codec", autoload=True, lazy=True)
+    await mock_plugins.load_autoload_plugins()
+    assert "lazy.codec" not in mock_plugins.loaded_plugins
+
+    assert await mock_plugins.create_neural_component("lazy.codec", "echo", {"x": 1}) == {"x": 1}
+    assert "lazy.codec" in mock_plugins.loaded_plugins
+
+
+async def test_unloading_a_lazy_plugin_before_its_first_call_keeps_it_unloaded(mock_plugins):
+    mock_plugins.add_plugin("lazy.codec", autoload=True, lazy=True)
+    await mock_plugins.load_autoload_plugins()
+
+    await mock_plugins.unload_plugin("lazy.codec")
+
+    assert "lazy.codec" not in mock_plugins.lazy_plugins
+    with pytest.raises(ValueError, match="not loaded"):
+        await mock_plugins.create_neural_component("lazy.codec", "echo", {})
+    assert "lazy.codec" not in mock_plugins.loaded_plugins
@@ -0,0 +1,46 @@
+from src.utils.metrics_registry import Histogram, MetricsRegistry
+
+
+def test_histogram_exports_cumulative_buckets_sum_and_count():
+    histogram = Histogram(buckets=(0.1, 1.0))
+    for value in (0.05, 0.1, 0.5, 3.0):
+        histogram.observe(value)
+    registry = MetricsRegistry()
+    registry.register_histogram("call_duration_seconds", "Call duration", lambda: [({"function": "echo"}, histogram)], unit="seconds")
+
+    lines = registry.export_prometheus().splitlines()
+
+    assert "# TYPE call_duration_seconds histogram" in lines
+    assert 'call_duration_seconds_bucket{function="echo",le="0.1"} 2' in lines
+    assert 'call_duration_seconds_bucket{function="echo",le="1.0"} 3' in lines
+    assert 'call_duration_seconds_bucket{function="echo",le="+Inf"} 4' in lines
+    assert 'call_duration_seconds_sum{function="echo"} 3.65' in lines
+    assert 'call_duration_seconds_count{function="echo"} 4' in lines
+
+
+def test_openmetrics_histogram_samples_carry_timestamps():
+    histogram = Histogram()
+    histogram.observe(0.2)
+    registry = MetricsRegistry()
+    registry.register_histogram("call_duration_seconds", "Call duration", lambda: [({}, histogram)], unit="seconds")
+
+    body = registry.export_openmetrics()
+
+    assert "# UNIT call_duration_seconds seconds" in body
+    assert any(line.startswith('call_duration_seconds_bucket{le="0.25"} 1 ') for line in body.splitlines())
+    assert body.endswith("# EOF\n")
+
+
+async def test_plugin_calls_are_observed_per_component_type(mock_plugins):
+    mock_plugins.add_plugin("metrics.echo")
+    await mock_plugins.load_plugin("metrics.echo")
+    await mock_plugins.enable_plugin("metrics.echo")
+
+    for _ in range(3):
+        await mock_plugins.create_neural_component("metrics.echo", "echo", {})
+
+    histogram = mock_plugins.plugin_call_durations[("metrics.echo", "echo")]
+    assert histogram.count == 3
+    assert histogram.cumulative_buckets()[-1] == ("+Inf", 3)
+    mock_plugins.reset_plugin_metrics()
+    assert mock_plugins.plugin_call_durations == {}
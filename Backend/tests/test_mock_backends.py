@@ -0,0 +1,27 @@
+from tests.mocks import MOCK_GPU_DATA
+
+
+async def test_mock_gpu_backend_reports_mock_devices(mock_gpu):
+    await mock_gpu.ensure_initialized()
+    await mock_gpu.ensure_initialized()
+
+    assert mock_gpu.calls["initialize"] == 1
+    assert mock_gpu.selected_device.device_id == MOCK_GPU_DATA["selected_device_id"]
+    assert [d["id"] for d in mock_gpu.get_all_devices()] == [d["id"] for d in MOCK_GPU_DATA["devices"]]
+
+
+async def test_mock_plugin_backend_loads_written_plugin(mock_plugins):
+    mock_plugins.add_plugin("test.echo")
+
+    await mock_plugins.load_plugin("test.echo")
+
+    assert "test.echo" in mock_plugins.loaded_plugins
+    assert mock_plugins.calls["load_plugin"] == 1
+
+
+def test_gpu_settings_served_from_mock_backend(client, mock_gpu):
+    response = client.get("/api/v1/gpu/settings")
+
+    assert response.status_code == 200
+    assert response.json()["current_device"]["id"] == MOCK_GPU_DATA["selected_device_id"]
+    assert mock_gpu.calls["get_all_devices"] == 1
@@ -0,0 +1,72 @@
+import pytest
+
+from src.api.endpoints.pipeline import PipelineStep, build_request, validate_pipeline
+
+
+def test_circular_and_backward_mappings_are_rejected():
+    with pytest.raises(ValueError, match="circular"):
+        validate_pipeline([PipelineStep(command="GET /api/v1/gpu", output_mappings={"devices.0.id": "0.device_id"})])
+    with pytest.raises(ValueError, match="circular"):
+        validate_pipeline([
+            PipelineStep(command="GET /api/v1/gpu", id="list"),
+            PipelineStep(command="POST /api/v1/gpu/select-device", output_mappings={"status": "list.anything"}),
+        ])
+
+
+def test_pipelines_cannot_nest_or_leave_the_api():
+    with pytest.raises(ValueError, match="cannot run another pipeline"):
+        validate_pipeline([PipelineStep(command="POST /api/v1/pipeline")])
+    with pytest.raises(ValueError, match="must target a route"):
+        validate_pipeline([PipelineStep(command="GET /static/index.html")])
+    with pytest.raises(ValueError, match="cannot run another pipeline"):
+        validate_pipeline([PipelineStep(command="POST /api/v1/gpu/../%70ipeline/")])
+    with pytest.raises(ValueError, match="must target a route"):
+        validate_pipeline([PipelineStep(command="GET /api/v1/../../static/index.html")])
+
+
+def test_path_values_are_encoded_and_cannot_change_the_route():
+    assert build_request("POST", "/api/v1/plugins/{plugin_id}/load", {"plugin_id": "a/b?force=1"}, {})["url"] == (
+        "/api/v1/plugins/a%2Fb%3Fforce%3D1/load"
+    )
+    with pytest.raises(ValueError, match="cannot run another pipeline"):
+        build_request("POST", "/api/v1/plugins/{plugin_id}", {"plugin_id": "../pipeline"}, {})
+
+
+def test_parameters_fill_the_path_then_the_body_or_query():
+    assert build_request("POST", "/api/v1/plugins/{plugin_id}/load", {"plugin_id": "a.b"}, {}) == {
+        "method": "POST", "url": "/api/v1/plugins/a.b/load", "params": {}, "json": None
+    }
+    assert build_request("GET", "/api/v1/gpu", {"verbose": True}, {}) == {
+        "method": "GET", "url": "/api/v1/gpu", "params": {"verbose": True}
+    }
+    with pytest.raises(ValueError, match="plugin_id"):
+        build_request("POST", "/api/v1/plugins/{plugin_id}/load", {}, {})
+
+
+def test_pipeline_feeds_one_steps_output_into_the_next(client):
+    response = client.post("/api/v1/pipeline", json={"steps": [
+        {"command": "GET /api/v1/gpu", "output_mappings": {"devices.1.id": "1.device_id"}},
+        {"command": "POST /api/v1/gpu/select-device"},
+        {"command": "GET /api/v1/gpu"},
+    ]})
+
+    body = response.json()
+    assert response.status_code == 200
+    assert body["completed"] is True
+    assert [result["status_code"] for result in body["results"]] == [200, 200, 200]
+    assert "Mock CPU" in body["results"][2]["output"]["selected_device"]
+
+
+def test_pipeline_stops_at_a_failed_step_unless_told_to_continue(client):
+    steps = [
+        {"command": "POST /api/v1/gpu/select-device", "parameters": {"device_id": "missing"}},
+        {"command": "GET /api/v1/gpu"},
+    ]
+    stopped = client.post("/api/v1/pipeline", json={"steps": steps}).json()
+    assert stopped["completed"] is False
+    assert len(stopped["results"]) == 1 and stopped["results"][0]["status_code"] == 400
+
+    steps[0]["continue_on_error"] = True
+    continued = client.post("/api/v1/pipeline", json={"steps": steps}).json()
+    assert continued["completed"] is True
+    assert [result["ok"] for result in continued["results"]] == [False, True]
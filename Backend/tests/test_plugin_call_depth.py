@@ -0,0 +1,25 @@
+import pytest
+
+from src.plugins.plugin_manager import PluginCallDepthExceeded
+from tests.mocks import BASIC_PLUGIN_SOURCE
+
+RECURSIVE_SOURCE = BASIC_PLUGIN_SOURCE.replace('''        return dict(config)''', '''        plugin_id = type(self).__module__  # extension modules are named after their plugin id
+        config["trace"].append(plugin_id)
+        plugins = self.services.request_service("plugin_manager")
+        return await plugins.create_neural_component(config["next"][plugin_id], "echo", config)''')
+
+
+async def test_mutually_recursive_plugins_stop_at_the_depth_limit(mock_plugins):
+    mock_plugins.max_call_depth = 5
+    mock_plugins.services.register_service("plugin_manager", mock_plugins)
+    for plugin_id in ("depth.ping", "depth.pong"):
+        mock_plugins.add_plugin(plugin_id, RECURSIVE_SOURCE)
+        await mock_plugins.load_plugin(plugin_id)
+        await mock_plugins.enable_plugin(plugin_id)
+
+    config = {"trace": [], "next": {"depth.ping": "depth.pong", "depth.pong": "depth.ping"}}
+    with pytest.raises(PluginCallDepthExceeded, match=r"\(5 >= 5\)"):
+        await mock_plugins.create_neural_component("depth.ping", "echo", config)
+
+    assert config["trace"] == ["depth.ping", "depth.pong"] * 2 + ["depth.ping"]
+    assert mock_plugins.plugin_metrics["depth.ping"].error_count == 3
@@ -0,0 +1,24 @@
+from tests.mocks import BASIC_PLUGIN_SOURCE
+
+STATEFUL_SOURCE = BASIC_PLUGIN_SOURCE.replace("""    async def initialize(self):
+        return True
+""", """    async def initialize(self):
+        self.gpu = self.services.request_service("gpu_manager")
+        self.history = []
+        return True
+""")
+
+
+async def test_clone_copies_state_but_shares_backend_handles(mock_plugins, mock_gpu):
+    mock_plugins.services.register_service("gpu_manager", mock_gpu)
+    mock_plugins.add_plugin("clone.source", STATEFUL_SOURCE)
+    await mock_plugins.load_plugin("clone.source")
+    source = mock_plugins.loaded_plugins["clone.source"]
+    source.history.append("trained")
+
+    await mock_plugins.clone_plugin_state("clone.source", "clone.copy")
+
+    clone = mock_plugins.loaded_plugins["clone.copy"]
+    assert clone.gpu is mock_gpu
+    assert clone.history == ["trained"] and clone.history is not source.history
+    assert clone.logger.name == "archetype.plugins.clone.copy"
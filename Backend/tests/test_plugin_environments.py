@@ -0,0 +1,26 @@
+from src.utils.config import Settings
+
+
+async def test_production_only_plugin_is_discovered_but_not_autoloaded_in_staging(mock_plugins):
+    mock_plugins.environment = "staging"
+    mock_plugins.add_plugin("env.production_only", autoload=True, load_in_environments=["production"])
+    mock_plugins.add_plugin("env.everywhere", autoload=True)
+
+    order = await mock_plugins.load_autoload_plugins()
+
+    assert "env.production_only" in mock_plugins.discover_extension_plugins()
+    assert "env.production_only" not in mock_plugins.loaded_plugins
+    assert "staging" in order.skipped["env.production_only"]
+    assert "env.everywhere" in mock_plugins.loaded_plugins
+
+
+def test_environment_is_read_from_archetype_env(monkeypatch):
+    monkeypatch.setenv("ARCHETYPE_ENVIRONMENT", "staging")
+
+    assert Settings(_env_file=None).environment == "staging"
+
+
+def test_get_environment_reports_the_active_environment(client, test_settings):
+    test_settings.environment = "staging"
+
+    assert client.get("/api/v1/config/environment").json() == {"environment": "staging"}
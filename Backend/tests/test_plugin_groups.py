@@ -0,0 +1,48 @@
+import pytest
+
+from tests.mocks import BASIC_PLUGIN_SOURCE
+
+FAILING_INIT_SOURCE = BASIC_PLUGIN_SOURCE.replace('''    async def initialize(self):
+        return True''', '''    async def initialize(self):
+        return False''')
+
+FAILING_CLEANUP_SOURCE = BASIC_PLUGIN_SOURCE.replace('''    async def cleanup(self):
+        pass''', '''    async def cleanup(self):
+        raise RuntimeError("cleanup failed")''')
+
+
+async def test_load_group_enables_members_in_dependency_order(mock_plugins):
+    mock_plugins.add_plugin("grp.app", group="stack", dependencies=["grp.base"])
+    mock_plugins.add_plugin("grp.base", group="stack")
+
+    members = await mock_plugins.load_group("stack")
+
+    assert members == ["grp.base", "grp.app"]
+    assert list(mock_plugins.loaded_plugins) == ["grp.base", "grp.app"]
+    assert mock_plugins.enabled_plugins == {"grp.base", "grp.app"}
+
+
+async def test_failing_member_leaves_no_group_member_loaded(mock_plugins):
+    mock_plugins.add_plugin("grp.first", group="chain")
+    mock_plugins.add_plugin("grp.second", FAILING_INIT_SOURCE, group="chain", dependencies=["grp.first"])
+    mock_plugins.add_plugin("grp.third", group="chain", dependencies=["grp.second"])
+
+    with pytest.raises(ValueError, match="Failed to load plugin group chain"):
+        await mock_plugins.load_group("chain")
+
+    assert mock_plugins.loaded_plugins == {}
+    assert mock_plugins.enabled_plugins == set()
+
+
+async def test_unload_group_rollback_restores_enabled_state(mock_plugins):
+    mock_plugins.add_plugin("grp.root", FAILING_CLEANUP_SOURCE, group="tree")
+    mock_plugins.add_plugin("grp.leaf_off", group="tree", dependencies=["grp.root"])
+    mock_plugins.add_plugin("grp.leaf_on", group="tree", dependencies=["grp.root"])
+    await mock_plugins.load_group("tree")
+    await mock_plugins.disable_plugin("grp.leaf_off")
+
+    with pytest.raises(ValueError, match="Failed to unload plugin group tree"):
+        await mock_plugins.unload_group("tree")
+
+    assert set(mock_plugins.loaded_plugins) == {"grp.root", "grp.leaf_off", "grp.leaf_on"}
+    assert mock_plugins.enabled_plugins == {"grp.root", "grp.leaf_on"}
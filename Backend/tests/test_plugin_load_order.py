@@ -0,0 +1,9 @@
+async def test_non_numeric_startup_priority_falls_back_to_the_default(mock_plugins, caplog):
+    mock_plugins.add_plugin("order.broken", autoload=True, startup_priority="first")
+    mock_plugins.add_plugin("order.early", autoload=True, startup_priority=-5)
+
+    order = await mock_plugins.load_autoload_plugins()
+
+    assert order.priorities == {"order.early": -5, "order.broken": 0}
+    assert {"order.broken", "order.early"} <= set(mock_plugins.loaded_plugins)
+    assert "non-numeric startup_priority 'first'" in caplog.text
@@ -0,0 +1,47 @@
+import pytest
+
+from tests.mocks import BASIC_PLUGIN_SOURCE
+
+
+async def load_locked(mock_plugins, plugin_id):
+    mock_plugins.add_plugin(plugin_id)
+    await mock_plugins.load_plugin(plugin_id)
+    await mock_plugins.enable_plugin(plugin_id)
+    return mock_plugins.update_plugin_lock()
+
+
+async def test_changed_plugin_module_invalidates_the_lock(mock_plugins):
+    entries = await load_locked(mock_plugins, "lock.pinned")
+    assert set(entries) == {"lock.pinned"}
+
+    mock_plugins.get_plugin_module_path("lock.pinned").write_text(BASIC_PLUGIN_SOURCE + "\n# changed\n")
+
+    report = mock_plugins.check_plugin_lock()
+    assert not report["matches"]
+    [mismatch] = report["mismatches"]
+    assert mismatch["plugin_id"] == "lock.pinned"
+    assert mismatch["sha256_hash"] != mismatch["locked_sha256_hash"]
+
+    await mock_plugins.unload_plugin("lock.pinned")
+    with pytest.raises(Exception, match="does not match locked hash"):
+        await mock_plugins.load_plugin("lock.pinned")
+
+
+async def test_clones_are_not_locked_and_are_forgotten_on_unload(mock_plugins):
+    await load_locked(mock_plugins, "lock.source")
+
+    await mock_plugins.clone_plugin_state("lock.source", "lock.copy")
+    assert set(mock_plugins.update_plugin_lock()) == {"lock.source"}
+    assert mock_plugins.check_plugin_lock()["matches"]
+
+    await mock_plugins.unload_plugin("lock.copy")
+    assert "lock.copy" not in mock_plugins.plugin_clones
+
+
+@pytest.mark.parametrize("new_id", ["", "nodots", "../escape.plugin", "Upper.Case"])
+async def test_clone_rejects_invalid_ids(mock_plugins, new_id):
+    await load_locked(mock_plugins, "lock.source")
+
+    with pytest.raises(ValueError, match="Invalid plugin id"):
+        await mock_plugins.clone_plugin_state("lock.source", new_id)
+    assert set(mock_plugins.loaded_plugins) == {"lock.source"}
@@ -0,0 +1,30 @@
+import pytest
+
+
+async def test_un_namespaced_plugin_still_loads_with_a_deprecation_warning(mock_plugins, caplog):
+    mock_plugins.add_plugin("processor")
+
+    await mock_plugins.load_plugin("processor")
+
+    assert "processor" in mock_plugins.loaded_plugins
+    assert "un-namespaced ids are deprecated" in caplog.text
+
+
+async def test_malformed_plugin_id_is_rejected(mock_plugins):
+    mock_plugins.add_plugin("bad id!")
+
+    assert "bad id!" not in mock_plugins.discover_extension_plugins()
+    with pytest.raises(ValueError):
+        await mock_plugins.load_plugin("bad id!")
+
+
+def test_legacy_plugin_id_requests_carry_a_deprecation_header(client, mock_plugins):
+    mock_plugins.add_plugin("processor")
+
+    legacy = client.post("/api/v1/plugins/processor/load")
+    namespaced = client.get("/api/v1/plugins/acme.processor/dependents")
+
+    assert legacy.status_code == 200
+    assert legacy.headers["deprecation"] == "true"
+    assert "processor is deprecated" in legacy.headers["warning"]
+    assert "deprecation" not in namespaced.headers
@@ -0,0 +1,75 @@
+import asyncio
+
+from tests.mocks import BASIC_PLUGIN_SOURCE
+
+PRINTING_SOURCE = BASIC_PLUGIN_SOURCE.replace('''        return dict(config)''', '''        import sys
+        for step in range(3):
+            print(f"{config['name']} step {step}")
+            await asyncio.sleep(0)
+        print(f"{config['name']} failed", file=sys.stderr)
+        return dict(config)''').replace("from src.plugins", "import asyncio\nfrom src.plugins")
+
+
+async def load_enabled(mock_plugins, plugin_id, source=PRINTING_SOURCE, **manifest):
+    mock_plugins.add_plugin(plugin_id, source, **manifest)
+    await mock_plugins.load_plugin(plugin_id)
+    await mock_plugins.enable_plugin(plugin_id)
+
+
+async def test_concurrent_plugin_output_goes_to_each_plugins_own_log(mock_plugins, capsys):
+    await load_enabled(mock_plugins, "output.first")
+    await load_enabled(mock_plugins, "output.second")
+
+    await asyncio.gather(
+        mock_plugins.create_neural_component("output.first", "echo", {"name": "first"}),
+        mock_plugins.create_neural_component("output.second", "echo", {"name": "second"}),
+    )
+    print("backend output")
+
+    first_log = mock_plugins.get_plugin_log_path("output.first").read_text()
+    second_log = mock_plugins.get_plugin_log_path("output.second").read_text()
+    assert "first step 2" in first_log and "second" not in first_log
+    assert "second step 2" in second_log and "first" not in second_log
+    assert "ERROR - first failed" in first_log
+    assert "backend output" in capsys.readouterr().out
+
+
+async def test_scheduled_task_output_goes_to_the_plugin_log(mock_plugins):
+    source = BASIC_PLUGIN_SOURCE + '''
+    async def execute_scheduled_task(self):
+        print("scheduled run")
+'''
+    await load_enabled(mock_plugins, "output.scheduled", source, schedule={"interval_ms": 10})
+
+    for _ in range(50):
+        if mock_plugins.scheduled_tasks["output.scheduled"]["runs"]:
+            break
+        await asyncio.sleep(0.01)
+    await mock_plugins.unload_plugin("output.scheduled")
+
+    assert "scheduled run" in mock_plugins.get_plugin_log_path("output.scheduled").read_text()
+
+
+async def test_output_from_every_plugin_entry_point_goes_to_the_plugin_log(mock_plugins, capsys):
+    source = BASIC_PLUGIN_SOURCE.replace("class Plugin", 'print("module import")\n\n\nclass Plugin').replace(
+        '''    async def cleanup(self):
+        pass''', '''    async def cleanup(self):
+        print("cleanup")''') + '''
+    async def run_self_test(self):
+        print("self-test")
+        return True, "ok"
+
+    def describe_api(self):
+        print("describe")
+        return []
+'''
+    await load_enabled(mock_plugins, "output.entry_points", source)
+
+    await mock_plugins.run_plugin_self_test("output.entry_points")
+    mock_plugins.describe_plugin_api("output.entry_points")
+    await mock_plugins.unload_plugin("output.entry_points")
+
+    log = mock_plugins.get_plugin_log_path("output.entry_points").read_text()
+    for line in ("module import", "self-test", "describe", "cleanup"):
+        assert line in log
+    assert capsys.readouterr().out == ""
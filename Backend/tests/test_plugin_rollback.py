@@ -0,0 +1,93 @@
+import json
+
+from tests.mocks import BASIC_PLUGIN_SOURCE
+
+HEALTHY_SOURCE = BASIC_PLUGIN_SOURCE + '''
+    async def health_check(self):
+        return True
+'''
+
+UNHEALTHY_SOURCE = BASIC_PLUGIN_SOURCE + '''
+    async def health_check(self):
+        return False
+'''
+
+
+async def load_enabled(mock_plugins, plugin_id):
+    plugin_path = mock_plugins.add_plugin(plugin_id, HEALTHY_SOURCE)
+    await mock_plugins.load_plugin(plugin_id)
+    await mock_plugins.enable_plugin(plugin_id)
+    return plugin_path
+
+
+async def test_failing_health_check_rolls_back_without_touching_the_source(mock_plugins):
+    plugin_path = await load_enabled(mock_plugins, "test.flaky")
+    healthy_hash = mock_plugins.plugin_hashes["test.flaky"]
+    mock_plugins.rollback_window_ms = 50
+
+    (plugin_path / "plugin.py").write_text(UNHEALTHY_SOURCE)
+    report = await mock_plugins.reload_plugin("test.flaky")
+    assert report["status"] == "reloaded"
+    await mock_plugins.rollback_watchers["test.flaky"]
+
+    assert mock_plugins.plugin_hashes["test.flaky"] == healthy_hash
+    assert await mock_plugins.loaded_plugins["test.flaky"].health_check() is True
+    assert "test.flaky" in mock_plugins.enabled_plugins
+    assert (plugin_path / "plugin.py").read_text() == UNHEALTHY_SOURCE
+
+
+async def test_failed_reload_rolls_back_without_repinning_the_lock(mock_plugins):
+    plugin_path = await load_enabled(mock_plugins, "test.locked")
+    healthy_hash = mock_plugins.plugin_hashes["test.locked"]
+    mock_plugins.update_plugin_lock()
+    lock_before = mock_plugins.lock_file_path.read_text()
+
+    (plugin_path / "plugin.py").write_text(UNHEALTHY_SOURCE)
+    report = await mock_plugins.reload_plugin("test.locked")
+
+    assert report["status"] == "rolled_back"
+    assert mock_plugins.plugin_hashes["test.locked"] == healthy_hash
+    assert mock_plugins.lock_file_path.read_text() == lock_before
+    assert json.loads(lock_before)["plugins"]["test.locked"]["sha256_hash"] == healthy_hash
+    assert (plugin_path / "plugin.py").read_text() == UNHEALTHY_SOURCE
+
+
+async def test_next_reload_picks_up_the_fixed_source(mock_plugins):
+    plugin_path = await load_enabled(mock_plugins, "test.fixed")
+    mock_plugins.rollback_window_ms = 50
+    (plugin_path / "plugin.py").write_text(UNHEALTHY_SOURCE)
+    await mock_plugins.reload_plugin("test.fixed")
+    await mock_plugins.rollback_watchers["test.fixed"]
+
+    (plugin_path / "plugin.py").write_text(HEALTHY_SOURCE + "\n# fixed\n")
+    await mock_plugins.reload_plugin("test.fixed")
+
+    assert mock_plugins.get_plugin_module_path("test.fixed") == plugin_path / "plugin.py"
+
+
+async def test_rollback_that_cannot_load_the_previous_version_is_recorded(mock_plugins, caplog):
+    plugin_path = await load_enabled(mock_plugins, "test.relocked")
+    mock_plugins.rollback_window_ms = 50
+    (plugin_path / "plugin.py").write_text(UNHEALTHY_SOURCE)
+    await mock_plugins.reload_plugin("test.relocked")
+    mock_plugins.update_plugin_lock()  # pins the unhealthy version, so the archived one no longer matches
+
+    await mock_plugins.rollback_watchers["test.relocked"]
+
+    assert "test.relocked" not in mock_plugins.loaded_plugins
+    info = await mock_plugins.get_plugin_info("test.relocked")
+    assert "does not match locked hash" in info["rollback_error"]
+    assert "Could not roll back plugin test.relocked" in caplog.text
+
+
+async def test_reload_reports_a_failed_rollback(mock_plugins):
+    plugin_path = await load_enabled(mock_plugins, "test.nohistory")
+    healthy_hash = mock_plugins.plugin_hashes["test.nohistory"]
+    mock_plugins._get_archived_version_path("test.nohistory", healthy_hash).unlink()
+
+    (plugin_path / "plugin.py").write_text("this is not python")
+    report = await mock_plugins.reload_plugin("test.nohistory")
+
+    assert report["status"] == "rollback_failed"
+    assert "no longer in its history" in report["error"]
+    assert (await mock_plugins.get_plugin_info("test.nohistory"))["rollback_error"] == report["error"]
@@ -0,0 +1,25 @@
+from tests.mocks import BASIC_PLUGIN_SOURCE
+
+SERVICE_SOURCE = BASIC_PLUGIN_SOURCE.replace('''    async def initialize(self):
+        return True''', '''    async def initialize(self):
+        self.channel = self.services.request_service("broadcast_channel")
+        return self.channel is not None''')
+
+
+class MockBroadcastChannel:
+    def __init__(self):
+        self.messages = []
+
+    async def broadcast_json(self, message):
+        self.messages.append(message)
+
+
+async def test_registered_service_reaches_the_plugin_at_initialization(mock_plugins):
+    channel = MockBroadcastChannel()
+    mock_plugins.services.register_service("broadcast_channel", channel)
+    mock_plugins.add_plugin("svc.listener", SERVICE_SOURCE)
+
+    await mock_plugins.load_plugin("svc.listener")
+
+    assert mock_plugins.loaded_plugins["svc.listener"].channel is channel
+
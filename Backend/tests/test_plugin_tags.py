@@ -0,0 +1,41 @@
+import pytest
+
+from tests.mocks import BASIC_PLUGIN_SOURCE, MockPluginBackend
+
+SECOND_VERSION_SOURCE = BASIC_PLUGIN_SOURCE.replace("return dict(config)", "return dict(config, version=2)")
+
+
+async def load_tagged(mock_plugins, plugin_id):
+    mock_plugins.add_plugin(plugin_id)
+    await mock_plugins.load_plugin(plugin_id)
+    await mock_plugins.enable_plugin(plugin_id)
+    return mock_plugins.tag_plugin_version(plugin_id, "stable")
+
+
+async def test_loading_a_tag_whose_version_left_the_history_fails_clearly(mock_plugins):
+    stable_hash = await load_tagged(mock_plugins, "tags.release")
+    mock_plugins.get_plugin_module_path("tags.release").write_text(SECOND_VERSION_SOURCE)
+    await mock_plugins.reload_plugin("tags.release")
+    current_hash = mock_plugins.plugin_hashes["tags.release"]
+
+    mock_plugins._get_archived_version_path("tags.release", stable_hash).unlink()
+
+    with pytest.raises(ValueError, match=f"Version {stable_hash[:12]} of plugin tags.release is no longer in its history"):
+        await mock_plugins.load_plugin_by_tag("tags.release", "stable")
+    assert mock_plugins.plugin_hashes["tags.release"] == current_hash
+    assert await mock_plugins.create_neural_component("tags.release", "echo", {}) == {"version": 2}
+
+
+async def test_tags_persist_in_the_plugin_registry(mock_plugins, tmp_path):
+    stable_hash = await load_tagged(mock_plugins, "tags.release")
+
+    reopened = MockPluginBackend(mock_plugins.plugin_directory, log_directory=tmp_path / "other_logs")
+
+    assert reopened.list_plugin_tags("tags.release") == {"stable": stable_hash}
+
+
+async def test_tagging_a_version_missing_from_history_fails(mock_plugins):
+    await load_tagged(mock_plugins, "tags.release")
+
+    with pytest.raises(ValueError, match="is not in its history"):
+        mock_plugins.tag_plugin_version("tags.release", "broken", sha256_hash="0" * 64)
@@ -0,0 +1,41 @@
+import pytest
+
+from src.core.profile_manager import ProfileManager
+from tests.mocks import MOCK_GPU_DATA
+
+
+@pytest.fixture
+def profile_manager(mock_gpu, mock_plugins, tmp_path):
+    return ProfileManager(mock_gpu, mock_plugins, tmp_path / "profiles")
+
+
+async def test_save_profile_initializes_a_lazy_gpu_manager(profile_manager, mock_gpu):
+    assert not mock_gpu.is_initialized()
+
+    profile = await profile_manager.save_profile("lazy")
+
+    assert profile["device_id"] == MOCK_GPU_DATA["selected_device_id"]
+
+
+async def test_load_profile_restores_enabled_state(profile_manager, mock_plugins):
+    for plugin_id in ("prof.on", "prof.off"):
+        mock_plugins.add_plugin(plugin_id)
+        await mock_plugins.load_plugin(plugin_id)
+    await mock_plugins.enable_plugin("prof.on")
+    await profile_manager.save_profile("work")
+
+    for plugin_id in ("prof.on", "prof.off"):
+        await mock_plugins.unload_plugin(plugin_id, force=True)
+    await profile_manager.load_profile("work")
+
+    assert set(mock_plugins.loaded_plugins) == {"prof.on", "prof.off"}
+    assert mock_plugins.enabled_plugins == {"prof.on"}
+
+
+async def test_load_older_profile_enables_newly_loaded_plugins(profile_manager, mock_plugins, tmp_path):
+    mock_plugins.add_plugin("prof.legacy")
+    (tmp_path / "profiles" / "old.json").write_text('{"name": "old", "plugins": ["prof.legacy"]}')
+
+    await profile_manager.load_profile("old")
+
+    assert "prof.legacy" in mock_plugins.enabled_plugins
@@ -0,0 +1,34 @@
+import pytest
+
+from src.utils.rate_limiter import RateLimiter
+
+
+@pytest.fixture
+def limited_client(backend_main, mock_gpu, mock_plugins, test_settings):
+    from fastapi.testclient import TestClient
+
+    test_settings.rate_limit_requests = 3
+    test_settings.rate_limit_window_seconds = 3600
+    backend_main.init_state_for_testing(mock_gpu, mock_plugins, test_settings)
+    return TestClient(backend_main.app)
+
+
+def test_remaining_requests_decrement_within_a_window():
+    limiter = RateLimiter(max_requests=2, window_seconds=3600)
+
+    first = limiter.hit("client")
+    second = limiter.hit("client")
+    third = limiter.hit("client")
+
+    assert [hit[:2] for hit in (first, second, third)] == [(True, 1), (True, 0), (False, 0)]
+    assert first[2] == second[2] == third[2] and first[2] % 3600 == 0
+    assert limiter.hit("other client")[:2] == (True, 1)
+
+
+def test_rate_limit_headers_appear_and_decrement(limited_client):
+    responses = [limited_client.get("/api/v1/health/live") for _ in range(4)]
+
+    assert [r.headers["x-rate-limit-limit"] for r in responses] == ["3"] * 4
+    assert [r.headers["x-rate-limit-remaining"] for r in responses] == ["2", "1", "0", "0"]
+    assert [r.status_code for r in responses] == [200, 200, 200, 429]
+    assert len({r.headers["x-rate-limit-reset"] for r in responses}) == 1
@@ -0,0 +1,64 @@
+import logging
+
+from src.utils.config import Settings
+from src.utils.request_logging import LogVerbosity, RequestLoggingMiddleware
+from tests.asgi import call_asgi, echo_app, http_scope
+
+
+def logging_middleware(status=200, **settings_fields):
+    settings = Settings(_env_file=None, **settings_fields)
+    return RequestLoggingMiddleware(echo_app(status), settings_provider=lambda: settings)
+
+
+async def test_none_verbosity_route_emits_no_log_lines(caplog):
+    caplog.set_level(logging.DEBUG, logger="archetype.requests")
+    app = logging_middleware(status=500, log_verbosity_overrides={"/quiet": LogVerbosity.NONE})
+
+    await call_asgi(app, http_scope("POST", "/quiet"), (b"payload",))
+
+    assert not [r for r in caplog.records if r.name == "archetype.requests"]
+
+
+async def test_verbose_logging_passes_the_body_through(caplog):
+    caplog.set_level(logging.INFO, logger="archetype.requests")
+    app = logging_middleware(default_log_verbosity=LogVerbosity.VERBOSE, log_verbosity_overrides={})
+
+    _, response_body = await call_asgi(app, http_scope("POST", "/api/v1/compute"), (b'{"a": ', b"1}"))
+
+    assert response_body == b'{"a": 1}'
+    [record] = [r for r in caplog.records if r.name == "archetype.requests"]
+    assert 'request: {"a": 1}' in record.getMessage()
+    assert 'response: {"a": 1}' in record.getMessage()
+
+
+async def test_minimal_verbosity_logs_only_errors(caplog):
+    caplog.set_level(logging.INFO, logger="archetype.requests")
+    settings = {"default_log_verbosity": LogVerbosity.MINIMAL, "log_verbosity_overrides": {}}
+
+    await call_asgi(logging_middleware(200, **settings), http_scope("GET", "/ok"))
+    await call_asgi(logging_middleware(404, **settings), http_scope("GET", "/missing"))
+
+    messages = [r.getMessage() for r in caplog.records if r.name == "archetype.requests"]
+    assert len(messages) == 1
+    assert "GET /missing -> 404" in messages[0]
+
+
+async def test_verbose_logging_masks_secret_fields(caplog):
+    caplog.set_level(logging.INFO, logger="archetype.requests")
+    app = logging_middleware(default_log_verbosity=LogVerbosity.VERBOSE, log_verbosity_overrides={})
+
+    await call_asgi(app, http_scope("POST", "/api/v1/users"), (b'{"name": "nina", "api_key": "s3cret"}',))
+
+    [record] = [r for r in caplog.records if r.name == "archetype.requests"]
+    assert "s3cret" not in record.getMessage()
+    assert '"api_key": "[REDACTED]"' in record.getMessage()
+
+
+async def test_truncated_body_naming_a_secret_is_dropped(caplog):
+    caplog.set_level(logging.INFO, logger="archetype.requests")
+    app = logging_middleware(default_log_verbosity=LogVerbosity.VERBOSE, log_verbosity_overrides={})
+
+    await call_asgi(app, http_scope("POST", "/api/v1/users"), (b'{"api_key": "s3cret"' + b" " * 70000 + b"}",))
+
+    [record] = [r for r in caplog.records if r.name == "archetype.requests"]
+    assert "s3cret" not in record.getMessage()
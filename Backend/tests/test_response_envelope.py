@@ -0,0 +1,37 @@
+import pytest
+
+
+@pytest.fixture
+def enveloped_client(client, test_settings):
+    test_settings.enable_response_envelope = True
+    return client
+
+
+@pytest.mark.parametrize("method, path, command, kwargs", [
+    ("GET", "/api/v1/gpu", "GET /api/v1/gpu", {}),
+    ("GET", "/api/v1/config/environment", "GET /api/v1/config/environment", {}),
+    ("POST", "/api/v1/gpu/select-device", "POST /api/v1/gpu/select-device", {"json": {"device_id": "cpu"}}),
+    ("GET", "/api/v1/health/live", "GET /api/v1/health/live", {}),
+])
+def test_every_command_is_wrapped_in_the_envelope(enveloped_client, method, path, command, kwargs):
+    response = enveloped_client.request(method, path, **kwargs)
+
+    assert response.status_code == 200
+    envelope = response.json()
+    assert envelope["api_version"] == "v1"
+    assert envelope["command"] == command
+    assert envelope["request_id"]
+    assert envelope["timestamp_ms"] > 0
+    assert envelope["duration_ms"] >= 0
+    assert envelope["data"] is not None
+
+
+def test_envelope_echoes_the_request_id(enveloped_client):
+    response = enveloped_client.get("/api/v1/config/environment", headers={"X-Request-ID": "req-191"})
+
+    assert response.json()["request_id"] == "req-191"
+    assert response.json()["data"] == {"environment": "development"}
+
+
+def test_envelope_is_off_by_default(client):
+    assert client.get("/api/v1/config/environment").json() == {"environment": "development"}
@@ -0,0 +1,38 @@
+from src.utils.config_reload import reload_settings
+from src.utils.route_filter import MAX_COUNTED_RULES, NOT_ALLOWLISTED, OTHER_RULES, RouteFilter
+
+
+def test_blocked_requests_are_counted_per_rule():
+    route_filter = RouteFilter()
+
+    for plugin_id in ("a.one", "b.two", "c.three"):
+        assert not route_filter.is_allowed(f"/api/v1/plugins/{plugin_id}", None, ["/api/v1/plugins/*"])
+    assert not route_filter.is_allowed("/api/v1/gpu", ["/api/v1/health*"], [])
+
+    assert route_filter.blocked_counts == {"deny:/api/v1/plugins/*": 3, NOT_ALLOWLISTED: 1}
+
+
+def test_counted_rules_are_capped():
+    route_filter = RouteFilter()
+    denylist = [f"/blocked/{index}" for index in range(MAX_COUNTED_RULES + 5)]
+
+    for path in denylist:
+        route_filter.is_allowed(path, None, denylist)
+
+    assert len(route_filter.blocked_counts) == MAX_COUNTED_RULES + 1
+    assert route_filter.blocked_counts[OTHER_RULES] == 5
+
+
+def test_allowlist_applies_and_reloads(client, backend_main, monkeypatch, tmp_path):
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setenv("ARCHETYPE_ROUTE_ALLOWLIST", '["/api/v1/gpu/settings"]')
+    reload_settings(backend_main.app.state)
+
+    assert client.get("/api/v1/gpu/settings").status_code == 200
+    assert client.get("/api/v1/plugins").status_code == 403
+
+    monkeypatch.setenv("ARCHETYPE_ROUTE_ALLOWLIST", '["/api/v1/plugins"]')
+    reload_settings(backend_main.app.state)
+
+    assert client.get("/api/v1/gpu/settings").status_code == 403
+    assert client.get("/api/v1/plugins").status_code == 200
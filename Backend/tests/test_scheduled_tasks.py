@@ -0,0 +1,38 @@
+import asyncio
+
+from tests.mocks import BASIC_PLUGIN_SOURCE
+
+SLEEPING_TASK_SOURCE = BASIC_PLUGIN_SOURCE.replace("from src.plugins", "import asyncio\nfrom src.plugins") + '''
+    async def execute_scheduled_task(self):
+        await asyncio.sleep(0.05)
+'''
+
+
+async def wait_for(condition, timeout_seconds=2.0):
+    deadline = asyncio.get_running_loop().time() + timeout_seconds
+    while not condition() and asyncio.get_running_loop().time() < deadline:
+        await asyncio.sleep(0.01)
+
+
+async def test_scheduled_task_reports_wall_time(mock_plugins):
+    mock_plugins.add_plugin("task.sleeper", SLEEPING_TASK_SOURCE, schedule={"interval_ms": 10})
+    await mock_plugins.load_plugin("task.sleeper")
+    await mock_plugins.enable_plugin("task.sleeper")
+
+    await wait_for(lambda: mock_plugins.scheduled_tasks["task.sleeper"]["runs"] >= 1)
+    [task] = mock_plugins.list_scheduled_tasks()
+    await mock_plugins.unload_plugin("task.sleeper")
+
+    assert task["wall_time_seconds"] >= 0.04
+
+
+async def test_disabled_plugin_skips_scheduled_runs(mock_plugins):
+    mock_plugins.add_plugin("task.idle", SLEEPING_TASK_SOURCE, schedule={"interval_ms": 10})
+    await mock_plugins.load_plugin("task.idle")
+
+    await wait_for(lambda: mock_plugins.scheduled_tasks["task.idle"]["skipped"] >= 2)
+    entry = mock_plugins.scheduled_tasks["task.idle"]
+    await mock_plugins.unload_plugin("task.idle")
+
+    assert entry["runs"] == 0
+    assert entry["skipped"] >= 2
@@ -0,0 +1,25 @@
+import pytest
+
+EXPECTED_HEADERS = {
+    "x-content-type-options": "nosniff",
+    "x-frame-options": "DENY",
+    "x-xss-protection": "1; mode=block",
+    "referrer-policy": "no-referrer",
+}
+
+
+@pytest.mark.parametrize("path", ["/api/v1/health/live", "/metrics", "/api/v1/no-such-route"])
+def test_every_response_carries_the_security_headers(client, path):
+    response = client.get(path)
+
+    for header, value in EXPECTED_HEADERS.items():
+        assert response.headers[header] == value
+    assert "content-security-policy" not in response.headers
+
+
+def test_content_security_policy_is_configurable(client, test_settings):
+    test_settings.csp_header = "default-src 'self'"
+
+    response = client.get("/api/v1/health/live")
+
+    assert response.headers["content-security-policy"] == "default-src 'self'"
@@ -0,0 +1,16 @@
+import types
+
+STARTUP_SUBSYSTEMS = {"gpu_manager", "model_factory", "training_engine", "plugin_manager", "profile_manager"}
+
+
+async def test_startup_timings_cover_every_subsystem(backend_main, mock_gpu, mock_plugins, test_settings, monkeypatch):
+    monkeypatch.setattr(backend_main, "Settings", lambda: test_settings)
+    monkeypatch.setattr(backend_main, "UniversalGPUManager", lambda mock_file=None: mock_gpu)
+    monkeypatch.setattr(backend_main, "PluginManager", lambda plugin_directory, log_max_bytes=None: mock_plugins)
+
+    async with backend_main.lifespan(backend_main.app):
+        timings = backend_main.app.state.startup_timings
+
+        assert set(timings) == STARTUP_SUBSYSTEMS
+        assert all(elapsed_ms >= 0 for elapsed_ms in timings.values())
+        assert isinstance(timings, types.MappingProxyType)
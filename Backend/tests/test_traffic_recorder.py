@@ -0,0 +1,58 @@
+import base64
+import json
+
+from src.utils.config import Settings
+from src.utils.traffic_recorder import TrafficRecorder, TrafficRecordingMiddleware
+from tests.asgi import call_asgi, echo_app, http_scope
+
+
+def recording_middleware(recorder, max_bytes):
+    settings = Settings(_env_file=None, traffic_record_max_body_bytes=max_bytes)
+    return TrafficRecordingMiddleware(echo_app(), recorder_provider=lambda: recorder, settings_provider=lambda: settings)
+
+
+def recorded_entries(recorder):
+    return [json.loads(recorder.queue.get_nowait()) for _ in range(recorder.queue.qsize())]
+
+
+async def test_records_request_and_response_without_consuming_the_body(tmp_path):
+    recorder = TrafficRecorder(tmp_path / "traffic.jsonl")
+    headers = [(b"content-type", b"application/json"), (b"x-api-key", b"secret")]
+
+    _, response_body = await call_asgi(
+        recording_middleware(recorder, 1024),
+        http_scope("POST", "/api/v1/compute", b"dry_run=1", headers),
+        (b'{"x": ', b"2}"),
+    )
+
+    assert response_body == b'{"x": 2}'
+    [entry] = recorded_entries(recorder)
+    assert entry["path"] == "/api/v1/compute?dry_run=1"
+    assert base64.b64decode(entry["body_base64"]) == b'{"x": 2}'
+    assert base64.b64decode(entry["response_body_base64"]) == b'{"x": 2}'
+    assert "x-api-key" not in entry["headers"]
+
+
+async def test_oversized_body_without_content_length_is_not_stored(tmp_path):
+    recorder = TrafficRecorder(tmp_path / "traffic.jsonl")
+    chunks = (b"a" * 600, b"b" * 600)
+
+    _, response_body = await call_asgi(recording_middleware(recorder, 1000), http_scope("POST", "/upload"), chunks)
+
+    assert response_body == b"".join(chunks)
+    [entry] = recorded_entries(recorder)
+    assert entry["body_base64"] is None
+    assert entry["response_body_base64"] is None
+    assert entry["response_status"] == 200
+
+
+async def test_secret_body_fields_are_not_recorded(tmp_path):
+    recorder = TrafficRecorder(tmp_path / "traffic.jsonl")
+    body = b'{"name": "nina", "api_key": "s3cret", "roles": ["viewer"]}'
+
+    await call_asgi(recording_middleware(recorder, 1024), http_scope("POST", "/api/v1/users"), (body,))
+
+    [entry] = recorded_entries(recorder)
+    for field in ("body_base64", "response_body_base64"):
+        recorded = json.loads(base64.b64decode(entry[field]))
+        assert recorded == {"name": "nina", "api_key": "[REDACTED]", "roles": ["viewer"]}
@@ -0,0 +1,67 @@
+import asyncio
+
+import torch
+
+from src.core.training_engine import TrainingEngine
+
+
+class _Device:
+    """Stands in for a torch.device of the given type"""
+
+    def __init__(self, type):
+        self.type = type
+
+
+class _GPUManager:
+    def __init__(self, device_type):
+        self.device = _Device(device_type)
+
+    async def ensure_initialized(self):
+        return True
+
+    def get_device(self):
+        return self.device
+
+
+class _ModelFactory:
+    def get_model(self, model_id):
+        return object()
+
+
+def _engine(device_type, monkeypatch):
+    engine = TrainingEngine(_GPUManager(device_type), _ModelFactory())
+    monkeypatch.setattr(engine, "_create_optimizer", lambda model, config: None)
+    monkeypatch.setattr(engine, "_create_criterion", lambda config: None)
+
+    async def no_batches(dataset_config, training_config):
+        return []
+    monkeypatch.setattr(engine, "_create_dataloader", no_batches)
+    return engine
+
+
+async def _run(engine):
+    training_id = await engine.start_training("model", {}, {"epochs": 0})
+    await asyncio.gather(*engine.training_tasks.values())
+    return await engine.get_training_status(training_id)
+
+
+async def test_peak_vram_is_measured_from_the_start_of_each_job(monkeypatch):
+    calls = []
+    monkeypatch.setattr(torch.cuda, "reset_peak_memory_stats", lambda device: calls.append("reset"), raising=False)
+    monkeypatch.setattr(torch.cuda, "max_memory_allocated", lambda device: calls.append("peak") or 3 * 1024 ** 2,
+                        raising=False)
+
+    status = await _run(_engine("cuda", monkeypatch))
+
+    assert status["status"] == "completed"
+    assert status["peak_vram_bytes"] == 3 * 1024 ** 2
+    assert calls == ["reset", "peak"]
+
+
+async def test_peak_vram_is_none_off_cuda(monkeypatch):
+    monkeypatch.setattr(torch.cuda, "max_memory_allocated", lambda device: 1 / 0, raising=False)
+
+    status = await _run(_engine("cpu", monkeypatch))
+
+    assert status["status"] == "completed"
+    assert status["peak_vram_bytes"] is None